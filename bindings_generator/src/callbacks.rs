@@ -46,6 +46,8 @@ const SPVC_MSL_TYPES: &[&str] = &[
 
 const NON_EXHAUSTIVE_TYPES: &[&str] = &["MslShaderVariableFormat"];
 
+const SERDE_TYPES: &[&str] = &["ResourceType"];
+
 impl ParseCallbacks for SpirvCrossCallbacks {
     fn item_name(&self, original_item_name: &str) -> Option<String> {
         // Keep the Spv prefix cause bindgen can't namespace.
@@ -179,6 +181,12 @@ impl ParseCallbacks for SpirvCrossCallbacks {
             return vec![String::from("#[non_exhaustive]")];
         };
 
+        if SERDE_TYPES.contains(&info.name) {
+            return vec![String::from(
+                "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]",
+            )];
+        };
+
         vec![]
     }
 }
@@ -9,11 +9,114 @@ struct __InternalCompilerHack : Compiler {
       SPIRVariable& get_variable(VariableID id) {
           return get<SPIRVariable>(id);
       };
+
+      bool needs_dummy_sampler_for_combined_images() {
+          DummySamplerForCombinedImageHandler handler(*this);
+          traverse_all_reachable_opcodes(get<SPIRFunction>(ir.default_entry_point), handler);
+          return handler.need_dummy_sampler;
+      }
+
+      struct DiscardHandler : OpcodeHandler {
+          bool has_discard = false;
+
+          // OpKill and OpTerminateInvocation are parsed into the block terminator,
+          // and are not visited as opcodes.
+          void set_current_block(const SPIRBlock &block) override {
+              if (block.terminator == SPIRBlock::Kill) {
+                  has_discard = true;
+              }
+          }
+
+          bool handle(spv::Op opcode, const uint32_t *, uint32_t) override {
+              if (has_discard || opcode == spv::OpDemoteToHelperInvocationEXT) {
+                  has_discard = true;
+                  return false;
+              }
+              return true;
+          }
+      };
+
+      SmallVector<VariableID> get_workgroup_variables() {
+          SmallVector<VariableID> variables;
+          ir.for_each_typed_id<SPIRVariable>([&](uint32_t id, SPIRVariable &variable) {
+              if (variable.storage == spv::StorageClassWorkgroup) {
+                  variables.push_back(id);
+              }
+          });
+          return variables;
+      }
+
+      void pin_interface_variable(VariableID id) {
+          // Ensure the ID is a variable.
+          get<SPIRVariable>(id);
+          if (check_active_interface_variables) {
+              active_interface_variables.insert(id);
+          }
+      }
+
+      ParsedIR& get_ir() {
+          return ir;
+      }
+
+      SmallVector<CombinedImageSampler>& get_combined_image_samplers() {
+          return combined_image_samplers;
+      }
+
+      std::unordered_set<VariableID>& get_active_interface_variables() {
+          return active_interface_variables;
+      }
+
+      bool& get_check_active_interface_variables() {
+          return check_active_interface_variables;
+      }
+
+      bool has_discard() {
+          DiscardHandler handler;
+          traverse_all_reachable_opcodes(get<SPIRFunction>(ir.default_entry_point), handler);
+          return handler.has_discard;
+      }
 };
 
 static_assert(sizeof(__InternalCompilerHack) == sizeof(Compiler),
     "Compiler can not be casted to __InternalCompilerHack" );
 
+#if SPIRV_CROSS_C_API_GLSL || SPIRV_CROSS_C_API_HLSL || SPIRV_CROSS_C_API_MSL || SPIRV_CROSS_C_API_CPP || SPIRV_CROSS_C_API_JSON
+#define SPVC_RS_HAS_GLSL_BASE 1
+// hack to get at protected fields of CompilerGLSL, which all compilable backends derive from.
+// this must not have any fields to maintain ABI, only static dispatch methods.
+struct __InternalCompilerGLSLHack : CompilerGLSL {
+    public:
+      SmallVector<std::string>& get_header_lines() {
+          return header_lines;
+      }
+
+      SmallVector<std::string>& get_forced_extensions() {
+          return forced_extensions;
+      }
+
+      std::unordered_set<uint32_t>& get_flattened_buffer_blocks() {
+          return flattened_buffer_blocks;
+      }
+};
+
+static_assert(sizeof(__InternalCompilerGLSLHack) == sizeof(CompilerGLSL),
+    "CompilerGLSL can not be casted to __InternalCompilerGLSLHack" );
+#endif
+
+// A copy of the mutable state of a compiler instance that is shared by all backends,
+// and the state of CompilerGLSL. State that is specific to the HLSL or MSL backends,
+// such as resource binding remaps, is not copied.
+struct spvc_rs_compiler_snapshot_s {
+    ParsedIR ir;
+    SmallVector<CombinedImageSampler> combined_image_samplers;
+    std::unordered_set<VariableID> active_interface_variables;
+    bool check_active_interface_variables;
+    SmallVector<std::string> header_lines;
+    SmallVector<std::string> forced_extensions;
+    std::unordered_set<uint32_t> flattened_buffer_blocks;
+};
+typedef struct spvc_rs_compiler_snapshot_s *spvc_rs_compiler_snapshot;
+
 /*
  * This is the native entrypoint for spirv-cross2/spirv-cross-sys.
  *
@@ -76,4 +179,181 @@ void spvc_rs_compiler_get_execution_model_indirect(spvc_compiler compiler, SpvEx
     *out = spvc_compiler_get_execution_model(compiler);
 }
 
+spvc_result spvc_rs_compiler_needs_dummy_sampler_for_combined_images(spvc_compiler compiler, spvc_bool* out) {
+    SPVC_BEGIN_SAFE_SCOPE
+    {
+        *out = static_cast<__InternalCompilerHack *>(compiler->compiler.get())->needs_dummy_sampler_for_combined_images();
+        return SPVC_SUCCESS;
+    }
+    SPVC_END_SAFE_SCOPE(compiler->context, SPVC_ERROR_INVALID_ARGUMENT)
+}
+
+spvc_result spvc_rs_compiler_has_discard(spvc_compiler compiler, spvc_bool* out) {
+    SPVC_BEGIN_SAFE_SCOPE
+    {
+        *out = static_cast<__InternalCompilerHack *>(compiler->compiler.get())->has_discard();
+        return SPVC_SUCCESS;
+    }
+    SPVC_END_SAFE_SCOPE(compiler->context, SPVC_ERROR_INVALID_ARGUMENT)
+}
+
+spvc_result spvc_rs_compiler_pin_interface_variable(spvc_compiler compiler, spvc_variable_id id) {
+    SPVC_BEGIN_SAFE_SCOPE
+    {
+        static_cast<__InternalCompilerHack *>(compiler->compiler.get())->pin_interface_variable(id);
+        return SPVC_SUCCESS;
+    }
+    SPVC_END_SAFE_SCOPE(compiler->context, SPVC_ERROR_INVALID_ARGUMENT)
+}
+
+spvc_result spvc_rs_compiler_get_workgroup_variables(spvc_compiler compiler, spvc_variable_id* out, size_t* length) {
+    SPVC_BEGIN_SAFE_SCOPE
+    {
+        auto variables = static_cast<__InternalCompilerHack *>(compiler->compiler.get())->get_workgroup_variables();
+        if (length != nullptr) {
+            *length = variables.size();
+        }
+
+        if (out != nullptr) {
+            for (auto &id: variables) {
+                *out = id;
+                out++;
+            }
+        }
+
+        return SPVC_SUCCESS;
+    }
+    SPVC_END_SAFE_SCOPE(compiler->context, SPVC_ERROR_OUT_OF_MEMORY)
+}
+
+spvc_result spvc_rs_compiler_get_spirv_words(spvc_compiler compiler, uint32_t* out, size_t* length) {
+    SPVC_BEGIN_SAFE_SCOPE
+    {
+        auto &words = static_cast<__InternalCompilerHack *>(compiler->compiler.get())->get_ir().spirv;
+        if (length != nullptr) {
+            *length = words.size();
+        }
+
+        if (out != nullptr) {
+            for (auto &word: words) {
+                *out = word;
+                out++;
+            }
+        }
+
+        return SPVC_SUCCESS;
+    }
+    SPVC_END_SAFE_SCOPE(compiler->context, SPVC_ERROR_OUT_OF_MEMORY)
+}
+
+spvc_bool spvc_rs_compiler_get_decoration_id_operand(spvc_compiler compiler, SpvId id, SpvDecoration decoration, SpvId* out) {
+    // ParsedIR only stores the operand of known literal decorations, so the ID operand of an
+    // OpDecorateId has to be read back from the SPIR-V binary.
+    auto *hack = static_cast<__InternalCompilerHack *>(compiler->compiler.get());
+    uint32_t word_offset = 0;
+    if (!hack->get_binary_offset_for_decoration(id, static_cast<spv::Decoration>(decoration), word_offset)) {
+        return SPVC_FALSE;
+    }
+
+    auto &words = hack->get_ir().spirv;
+    if (word_offset >= words.size()) {
+        return SPVC_FALSE;
+    }
+
+    *out = words[word_offset];
+    return SPVC_TRUE;
+}
+
+spvc_bool spvc_rs_compiler_type_is_forward_pointer(spvc_compiler compiler, spvc_type_id id) {
+    // The OpTypePointer that completes an OpTypeForwardPointer replaces its type in ParsedIR,
+    // so the forward declaration has to be found in the SPIR-V binary.
+    auto &words = static_cast<__InternalCompilerHack *>(compiler->compiler.get())->get_ir().spirv;
+    size_t offset = 5;
+    while (offset < words.size()) {
+        uint32_t count = words[offset] >> 16;
+        auto op = static_cast<spv::Op>(words[offset] & 0xffff);
+        if (count == 0 || op == spv::OpFunction) {
+            break;
+        }
+
+        if (op == spv::OpTypeForwardPointer && offset + 1 < words.size() && words[offset + 1] == id) {
+            return SPVC_TRUE;
+        }
+
+        offset += count;
+    }
+
+    return SPVC_FALSE;
+}
+
+spvc_result spvc_rs_context_copy_parsed_ir(spvc_context context, spvc_compiler compiler, spvc_parsed_ir* out) {
+    SPVC_BEGIN_SAFE_SCOPE
+    {
+        std::unique_ptr<spvc_parsed_ir_s> pir(new (std::nothrow) spvc_parsed_ir_s);
+        if (!pir) {
+            context->report_error("Out of memory.");
+            return SPVC_ERROR_OUT_OF_MEMORY;
+        }
+
+        pir->context = context;
+        pir->parsed = static_cast<__InternalCompilerHack *>(compiler->compiler.get())->get_ir();
+        *out = pir.get();
+        context->allocations.push_back(std::move(pir));
+        return SPVC_SUCCESS;
+    }
+    SPVC_END_SAFE_SCOPE(context, SPVC_ERROR_OUT_OF_MEMORY)
+}
+
+spvc_result spvc_rs_compiler_snapshot_create(spvc_compiler compiler, spvc_rs_compiler_snapshot* out) {
+    SPVC_BEGIN_SAFE_SCOPE
+    {
+        std::unique_ptr<spvc_rs_compiler_snapshot_s> snapshot(new spvc_rs_compiler_snapshot_s);
+        auto *base = static_cast<__InternalCompilerHack *>(compiler->compiler.get());
+        snapshot->ir = base->get_ir();
+        snapshot->combined_image_samplers = base->get_combined_image_samplers();
+        snapshot->active_interface_variables = base->get_active_interface_variables();
+        snapshot->check_active_interface_variables = base->get_check_active_interface_variables();
+
+#if SPVC_RS_HAS_GLSL_BASE
+        if (compiler->backend != SPVC_BACKEND_NONE) {
+            auto *glsl = static_cast<__InternalCompilerGLSLHack *>(compiler->compiler.get());
+            snapshot->header_lines = glsl->get_header_lines();
+            snapshot->forced_extensions = glsl->get_forced_extensions();
+            snapshot->flattened_buffer_blocks = glsl->get_flattened_buffer_blocks();
+        }
+#endif
+
+        *out = snapshot.release();
+        return SPVC_SUCCESS;
+    }
+    SPVC_END_SAFE_SCOPE(compiler->context, SPVC_ERROR_OUT_OF_MEMORY)
+}
+
+spvc_result spvc_rs_compiler_snapshot_restore(spvc_compiler compiler, spvc_rs_compiler_snapshot snapshot) {
+    SPVC_BEGIN_SAFE_SCOPE
+    {
+        auto *base = static_cast<__InternalCompilerHack *>(compiler->compiler.get());
+        base->get_ir() = snapshot->ir;
+        base->get_combined_image_samplers() = snapshot->combined_image_samplers;
+        base->get_active_interface_variables() = snapshot->active_interface_variables;
+        base->get_check_active_interface_variables() = snapshot->check_active_interface_variables;
+
+#if SPVC_RS_HAS_GLSL_BASE
+        if (compiler->backend != SPVC_BACKEND_NONE) {
+            auto *glsl = static_cast<__InternalCompilerGLSLHack *>(compiler->compiler.get());
+            glsl->get_header_lines() = snapshot->header_lines;
+            glsl->get_forced_extensions() = snapshot->forced_extensions;
+            glsl->get_flattened_buffer_blocks() = snapshot->flattened_buffer_blocks;
+        }
+#endif
+
+        return SPVC_SUCCESS;
+    }
+    SPVC_END_SAFE_SCOPE(compiler->context, SPVC_ERROR_OUT_OF_MEMORY)
+}
+
+void spvc_rs_compiler_snapshot_free(spvc_rs_compiler_snapshot snapshot) {
+    delete snapshot;
+}
+
 } // extern "C"
\ No newline at end of file
@@ -15,4 +15,28 @@ spvc_bool spvc_rs_type_is_pointer(spvc_type type);
 
 spvc_bool spvc_rs_type_is_forward_pointer(spvc_type type);
 
-void spvc_rs_compiler_get_execution_model_indirect(spvc_compiler compiler, SpvExecutionModel* out);
\ No newline at end of file
+void spvc_rs_compiler_get_execution_model_indirect(spvc_compiler compiler, SpvExecutionModel* out);
+
+spvc_result spvc_rs_compiler_needs_dummy_sampler_for_combined_images(spvc_compiler compiler, spvc_bool* out);
+
+spvc_result spvc_rs_compiler_has_discard(spvc_compiler compiler, spvc_bool* out);
+
+spvc_result spvc_rs_compiler_pin_interface_variable(spvc_compiler compiler, spvc_variable_id id);
+
+spvc_result spvc_rs_compiler_get_workgroup_variables(spvc_compiler compiler, spvc_variable_id* out, size_t* length);
+
+spvc_result spvc_rs_compiler_get_spirv_words(spvc_compiler compiler, uint32_t* out, size_t* length);
+
+spvc_bool spvc_rs_compiler_get_decoration_id_operand(spvc_compiler compiler, SpvId id, SpvDecoration decoration, SpvId* out);
+
+spvc_bool spvc_rs_compiler_type_is_forward_pointer(spvc_compiler compiler, spvc_type_id id);
+
+spvc_result spvc_rs_context_copy_parsed_ir(spvc_context context, spvc_compiler compiler, spvc_parsed_ir* out);
+
+typedef struct spvc_rs_compiler_snapshot_s *spvc_rs_compiler_snapshot;
+
+spvc_result spvc_rs_compiler_snapshot_create(spvc_compiler compiler, spvc_rs_compiler_snapshot* out);
+
+spvc_result spvc_rs_compiler_snapshot_restore(spvc_compiler compiler, spvc_rs_compiler_snapshot snapshot);
+
+void spvc_rs_compiler_snapshot_free(spvc_rs_compiler_snapshot snapshot);
\ No newline at end of file
@@ -2194,6 +2194,7 @@ pub enum CompilerBackend {
     Json = 5,
 }
 #[repr(i32)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(
     Debug, Copy, Clone, Hash, PartialEq, Eq, num_derive :: FromPrimitive, num_derive :: ToPrimitive,
 )]
@@ -4080,3 +4081,85 @@ extern "C" {
         out: *mut SpvExecutionModel,
     );
 }
+extern "C" {
+    #[must_use]
+    pub fn spvc_rs_compiler_needs_dummy_sampler_for_combined_images(
+        compiler: spvc_compiler,
+        out: *mut crate::ctypes::spvc_bool,
+    ) -> spvc_result;
+}
+extern "C" {
+    #[must_use]
+    pub fn spvc_rs_compiler_has_discard(
+        compiler: spvc_compiler,
+        out: *mut crate::ctypes::spvc_bool,
+    ) -> spvc_result;
+}
+extern "C" {
+    #[must_use]
+    pub fn spvc_rs_compiler_pin_interface_variable(
+        compiler: spvc_compiler,
+        id: VariableId,
+    ) -> spvc_result;
+}
+extern "C" {
+    #[must_use]
+    pub fn spvc_rs_compiler_get_workgroup_variables(
+        compiler: spvc_compiler,
+        out: *mut VariableId,
+        length: *mut usize,
+    ) -> spvc_result;
+}
+extern "C" {
+    #[must_use]
+    pub fn spvc_rs_compiler_get_spirv_words(
+        compiler: spvc_compiler,
+        out: *mut u32,
+        length: *mut usize,
+    ) -> spvc_result;
+}
+extern "C" {
+    pub fn spvc_rs_compiler_get_decoration_id_operand(
+        compiler: spvc_compiler,
+        id: SpvId,
+        decoration: SpvDecoration,
+        out: *mut SpvId,
+    ) -> crate::ctypes::spvc_bool;
+}
+extern "C" {
+    pub fn spvc_rs_compiler_type_is_forward_pointer(
+        compiler: spvc_compiler,
+        id: TypeId,
+    ) -> crate::ctypes::spvc_bool;
+}
+extern "C" {
+    #[must_use]
+    pub fn spvc_rs_context_copy_parsed_ir(
+        context: spvc_context,
+        compiler: spvc_compiler,
+        out: *mut spvc_parsed_ir,
+    ) -> spvc_result;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct spvc_rs_compiler_snapshot_s {
+    _unused: [u8; 0],
+}
+pub type spvc_rs_compiler_snapshot = *mut spvc_rs_compiler_snapshot_s;
+extern "C" {
+    #[must_use]
+    pub fn spvc_rs_compiler_snapshot_create(
+        compiler: spvc_compiler,
+        out: *mut spvc_rs_compiler_snapshot,
+    ) -> spvc_result;
+}
+extern "C" {
+    #[must_use]
+    pub fn spvc_rs_compiler_snapshot_restore(
+        compiler: spvc_compiler,
+        snapshot: spvc_rs_compiler_snapshot,
+    ) -> spvc_result;
+}
+extern "C" {
+    pub fn spvc_rs_compiler_snapshot_free(snapshot: spvc_rs_compiler_snapshot);
+}
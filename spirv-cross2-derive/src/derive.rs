@@ -1,8 +1,8 @@
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Data, DeriveInput, Expr, Fields, Token, Type};
+use syn::{Attribute, Data, DeriveInput, Expr, Field, Fields, Token, Type, Visibility};
 
 struct CompilerOption {
     field_name: Ident,
@@ -11,10 +11,62 @@ struct CompilerOption {
     is_bool: bool,
 }
 
+/// An option that is handled by spirv-cross2 and not passed to SPIRV-Cross.
+struct LocalOption {
+    field_name: Ident,
+    default: Option<Expr>,
+}
+
 struct Expansions {
     field_name: Ident,
 }
 
+/// A field of the generated overrides struct.
+struct OverrideField {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    field_name: Ident,
+    ty: TokenStream,
+}
+
+/// The type of the overrides for an `#[expand]` field.
+///
+/// This is `#[expand(Type)]` if given, otherwise the type of the field
+/// suffixed with `Overrides`.
+fn expand_overrides_type(field: &Field, attr: &Attribute) -> syn::Result<TokenStream> {
+    if let syn::Meta::List(list) = &attr.meta {
+        let ty = list.parse_args::<Type>()?;
+        return Ok(quote! { #ty });
+    }
+
+    let Type::Path(type_path) = &field.ty else {
+        return Err(syn::Error::new(
+            field.ty.span(),
+            "The overrides type of this field must be specified with #[expand(Type)].",
+        ));
+    };
+
+    let mut path = type_path.path.clone();
+    let last = path.segments.last_mut().unwrap();
+    last.ident = format_ident!("{}Overrides", last.ident);
+    Ok(quote! { #path })
+}
+
+/// Build the field of the overrides struct for a field of the options struct.
+fn override_field(field: &Field, ty: TokenStream) -> OverrideField {
+    OverrideField {
+        attrs: field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("doc") || a.path().is_ident("deprecated"))
+            .cloned()
+            .collect(),
+        vis: field.vis.clone(),
+        field_name: field.ident.clone().unwrap(),
+        ty,
+    }
+}
+
 pub(crate) fn do_derive(input: DeriveInput) -> syn::Result<TokenStream> {
     let Data::Struct(data) = input.data else {
         return Err(syn::Error::new(
@@ -34,6 +86,19 @@ pub(crate) fn do_derive(input: DeriveInput) -> syn::Result<TokenStream> {
                 unsafe fn apply<'a>(&self, _options: ::spirv_cross_sys::spvc_compiler_options, _root: impl ContextRooted + Copy)
                     -> crate::error::Result<()>
                 { Ok(()) }
+
+                type Overrides = Self;
+
+                fn merge(&mut self, _overrides: &Self) {}
+            }
+
+            impl #name {
+                /// Merge a set of overrides into these options.
+                ///
+                /// There are no options to merge.
+                pub fn merge(&mut self, overrides: &Self) {
+                    crate::compile::sealed::ApplyCompilerOptions::merge(self, overrides)
+                }
             }
         };
 
@@ -47,6 +112,25 @@ pub(crate) fn do_derive(input: DeriveInput) -> syn::Result<TokenStream> {
         ));
     };
 
+    let mut override_fields = Vec::new();
+    for field in &fields.named {
+        let overrides_ty =
+            if let Some(attr) = field.attrs.iter().find(|a| a.path().is_ident("expand")) {
+                expand_overrides_type(field, attr)?
+            } else if field
+                .attrs
+                .iter()
+                .any(|a| a.path().is_ident("option") || a.path().is_ident("local_option"))
+            {
+                let ty = &field.ty;
+                quote! { ::std::option::Option<#ty> }
+            } else {
+                continue;
+            };
+
+        override_fields.push(override_field(field, overrides_ty));
+    }
+
     let options: Vec<_> = fields
         .named
         .iter()
@@ -82,6 +166,28 @@ pub(crate) fn do_derive(input: DeriveInput) -> syn::Result<TokenStream> {
         })
         .collect();
 
+    let local_options: Vec<_> = fields
+        .named
+        .iter()
+        .filter_map(|field| {
+            let ident = field.ident.clone().unwrap();
+            let attr = field
+                .attrs
+                .iter()
+                .find(|a| a.path().is_ident("local_option"))?;
+
+            let default = match &attr.meta {
+                syn::Meta::List(list) => Some(list.parse_args::<Expr>().ok()?),
+                _ => None,
+            };
+
+            Some(LocalOption {
+                field_name: ident,
+                default,
+            })
+        })
+        .collect();
+
     let expands: Vec<_> = fields
         .named
         .iter()
@@ -98,6 +204,7 @@ pub(crate) fn do_derive(input: DeriveInput) -> syn::Result<TokenStream> {
     let mut setters = Vec::new();
     let mut defaults: Vec<TokenStream> = Vec::new();
     let mut expanders: Vec<TokenStream> = Vec::new();
+    let mut mergers: Vec<TokenStream> = Vec::new();
 
     for option in options {
         let path = option.path;
@@ -127,8 +234,38 @@ pub(crate) fn do_derive(input: DeriveInput) -> syn::Result<TokenStream> {
             }
         };
 
+        let merger = quote! {
+            if let Some(value) = &overrides.#field {
+                self.#field = ::std::clone::Clone::clone(value);
+            }
+        };
+
         setters.push(setter);
         defaults.push(default_setter);
+        mergers.push(merger);
+    }
+
+    for option in local_options {
+        let field = option.field_name;
+
+        let default_setter = if let Some(default) = option.default {
+            quote! {
+                #field: #default,
+            }
+        } else {
+            quote! {
+                #field: Default::default(),
+            }
+        };
+
+        let merger = quote! {
+            if let Some(value) = &overrides.#field {
+                self.#field = ::std::clone::Clone::clone(value);
+            }
+        };
+
+        defaults.push(default_setter);
+        mergers.push(merger);
     }
 
     for expands in expands {
@@ -140,29 +277,88 @@ pub(crate) fn do_derive(input: DeriveInput) -> syn::Result<TokenStream> {
              #field: Default::default(),
         };
 
+        let merger = quote! {
+            crate::compile::sealed::ApplyCompilerOptions::merge(&mut self.#field, &overrides.#field);
+        };
+
         expanders.push(expander);
         defaults.push(default_setter);
+        mergers.push(merger);
     }
 
     let name = input.ident;
+    let vis = input.vis;
+    let overrides_name = format_ident!("{}Overrides", name);
+    let non_exhaustive = input
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("non_exhaustive"));
+
+    let overrides_fields = override_fields.into_iter().map(|field| {
+        let OverrideField {
+            attrs,
+            vis,
+            field_name,
+            ty,
+        } = field;
+        quote! {
+            #(#attrs)*
+            #vis #field_name: #ty,
+        }
+    });
+
+    let overrides_doc = format!(
+        " Overrides for [`{name}`], to be merged with [`{name}::merge`].\n\n \
+        Only options that are `Some` replace the options they are merged into, \
+        so an override can also reset an option to its default value."
+    );
+
     // Build the output, possibly using quasi-quotation
     let expanded = quote! {
+        #[doc = #overrides_doc]
+        #non_exhaustive
+        #[derive(Debug, Default, Clone)]
+        #vis struct #overrides_name {
+            #(#overrides_fields)*
+        }
+
+        // Deprecated options are still applied and merged.
+        #[allow(deprecated)]
         impl crate::compile::sealed::ApplyCompilerOptions for #name {
             unsafe fn apply<'a>(&self, options: ::spirv_cross_sys::spvc_compiler_options, root: impl ContextRooted + Copy)
                 -> crate::error::Result<()>
             {
                 unsafe {
-                    #(#expanders)*;
+                    #(#setters)*;
                 }
 
+                // Nested option sets are applied last, so that raw options
+                // take precedence over typed options.
                 unsafe {
-                    #(#setters)*;
+                    #(#expanders)*;
                 }
 
                 Ok(())
             }
+
+            type Overrides = #overrides_name;
+
+            fn merge(&mut self, overrides: &#overrides_name) {
+                #(#mergers)*
+            }
+        }
+
+        impl #name {
+            /// Merge a set of overrides into these options.
+            ///
+            /// Only options that are set in `overrides` replace the options in `self`.
+            /// Nested option sets are merged recursively.
+            pub fn merge(&mut self, overrides: &#overrides_name) {
+                crate::compile::sealed::ApplyCompilerOptions::merge(self, overrides)
+            }
         }
 
+         #[allow(deprecated)]
          impl ::std::default::Default for #name {
             fn default() -> Self {
                 Self {
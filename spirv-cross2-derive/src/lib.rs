@@ -3,7 +3,7 @@ mod derive;
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
 
-#[proc_macro_derive(CompilerOptions, attributes(option, expand))]
+#[proc_macro_derive(CompilerOptions, attributes(option, local_option, expand))]
 pub fn compiler_options_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree
     let input = parse_macro_input!(input as DeriveInput);
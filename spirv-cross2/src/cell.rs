@@ -63,6 +63,8 @@ impl CrossAllocationCell {
     /// This allows for instances to be stored without keeping a reference to the
     /// context separately.
     pub(crate) fn into_compiler<T: Target>(self, spirv: Module) -> error::Result<Compiler<T>> {
+        validate_header(&spirv)?;
+
         unsafe {
             let mut ir = std::ptr::null_mut();
             sys::spvc_context_parse_spirv(
@@ -73,6 +75,39 @@ impl CrossAllocationCell {
             )
             .ok(&self)?;
 
+            let mut compiler = self.create_compiler(ir)?;
+            compiler.source_language = spirv.source_language();
+            Ok(compiler)
+        }
+    }
+
+    /// Create a compiler instance from a copy of the parsed module of another compiler instance.
+    ///
+    /// The copy is allocated in this context, so the resulting instance does not share
+    /// any state with `other`.
+    pub(crate) fn copy_compiler<T: Target, U>(
+        self,
+        other: &Compiler<U>,
+    ) -> error::Result<Compiler<T>> {
+        unsafe {
+            let mut ir = std::ptr::null_mut();
+            sys::spvc_rs_context_copy_parsed_ir(self.0 .0.as_ptr(), other.ptr.as_ptr(), &mut ir)
+                .ok(&self)?;
+
+            let mut compiler = self.create_compiler(ir)?;
+            compiler.source_language = other.source_language;
+            Ok(compiler)
+        }
+    }
+
+    /// Create a compiler instance that takes ownership of parsed IR allocated in this context.
+    ///
+    /// SAFETY: `ir` must have been allocated in this context, and must not be used afterwards.
+    pub(crate) unsafe fn create_compiler<T: Target>(
+        self,
+        ir: sys::spvc_parsed_ir,
+    ) -> error::Result<Compiler<T>> {
+        unsafe {
             let mut compiler = std::ptr::null_mut();
             sys::spvc_context_create_compiler(
                 self.0 .0.as_ptr(),
@@ -91,6 +126,14 @@ impl CrossAllocationCell {
         }
     }
 
+    /// Create another cell to the same context.
+    ///
+    /// SAFETY: compiler instances created from the shared cell must not be
+    /// used concurrently, or be returned to the user.
+    pub(crate) unsafe fn share(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+
     /// Get a pointer to the context.
     ///
     /// SAFETY: must have unique ownership.
@@ -104,6 +147,42 @@ impl CrossAllocationCell {
     }
 }
 
+/// The SPIR-V magic number.
+pub(crate) const SPIRV_MAGIC: u32 = 0x07230203;
+
+/// The universal limit on the Result `<id>` bound, as per the SPIR-V specification.
+const SPIRV_MAX_ID_BOUND: u32 = 4_194_303;
+
+/// Check the SPIR-V header before handing the module to SPIRV-Cross.
+///
+/// SPIRV-Cross will happily attempt to allocate metadata for every ID up to the
+/// declared bound, which can abort the process on malformed input.
+fn validate_header(spirv: &Module) -> error::Result<()> {
+    let [magic, _version, _generator, bound, _schema, ..] = &*spirv.0 else {
+        return Err(SpirvCrossError::InvalidSpirv(String::from(
+            "SPIR-V module is too small to contain a header",
+        )));
+    };
+
+    let bound = match magic.0 {
+        SPIRV_MAGIC => bound.0,
+        magic if magic.swap_bytes() == SPIRV_MAGIC => bound.0.swap_bytes(),
+        _ => {
+            return Err(SpirvCrossError::InvalidSpirv(String::from(
+                "Invalid SPIR-V magic number",
+            )))
+        }
+    };
+
+    if bound == 0 || bound > SPIRV_MAX_ID_BOUND {
+        return Err(SpirvCrossError::InvalidSpirv(format!(
+            "SPIR-V ID bound {bound} is out of range"
+        )));
+    }
+
+    Ok(())
+}
+
 impl Drop for CrossAllocationCellInner {
     fn drop(&mut self) {
         unsafe { sys::spvc_context_destroy(self.0.as_ptr()) }
@@ -128,3 +207,101 @@ impl ContextRooted for &PhantomCompiler {
         self.ctx.0 .0
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::error::SpirvCrossError;
+    use crate::Compiler;
+    use crate::{targets, Module};
+
+    #[test]
+    pub fn parse_malformed() {
+        let bad: [&[u32]; 4] = [
+            &[],
+            &[0x07230203, 0x00010000, 0, 10],
+            &[0xdeadbeef, 0x00010000, 0, 10, 0],
+            &[0x07230203, 0x00010000, 0, u32::MAX, 0],
+        ];
+
+        for words in bad {
+            let compiler = Compiler::<targets::None>::new(Module::from_words(words));
+            assert!(matches!(compiler, Err(SpirvCrossError::InvalidSpirv(_))));
+        }
+    }
+
+    #[test]
+    pub fn parse_error_message() {
+        // The header is valid, but the OpCapability instruction claims more words than there are.
+        let words = [0x07230203, 0x00010000, 0, 10, 0, (5 << 16) | 17, 1];
+
+        let compiler = Compiler::<targets::None>::new(Module::from_words(&words));
+        let Err(SpirvCrossError::InvalidSpirv(message)) = compiler else {
+            panic!("malformed module was parsed");
+        };
+
+        // The message is the last error reported by SPIRV-Cross.
+        assert_eq!("SPIR-V instruction goes out of bounds.", message);
+    }
+
+    #[test]
+    pub fn from_bytes() -> Result<(), SpirvCrossError> {
+        static BASIC_SPV: &[u8] = include_bytes!("../basic.spv");
+
+        Compiler::<targets::None>::new(Module::from_bytes(BASIC_SPV)?)?;
+
+        let swapped: Vec<u8> = BASIC_SPV
+            .chunks_exact(4)
+            .flat_map(|word| [word[3], word[2], word[1], word[0]])
+            .collect();
+        Compiler::<targets::None>::new(Module::from_bytes(&swapped)?)?;
+
+        assert!(matches!(
+            Module::from_bytes(&BASIC_SPV[..7]),
+            Err(SpirvCrossError::InvalidModule(_))
+        ));
+        assert!(matches!(
+            Module::from_bytes(&[0; 8]),
+            Err(SpirvCrossError::InvalidModule(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn new_for_entry_points() -> Result<(), SpirvCrossError> {
+        static BASIC_SPV: &[u8] = include_bytes!("../basic.spv");
+
+        let compilers =
+            Compiler::<targets::None>::new_for_entry_points(Module::from_bytes(BASIC_SPV)?)?;
+        assert_eq!(1, compilers.len());
+
+        let compiler = &compilers[0];
+        assert_eq!(spirv::ExecutionModel::Fragment, compiler.execution_model()?);
+        assert!(!compiler
+            .shader_resources()?
+            .all_resources()?
+            .uniform_buffers
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn spirv_words() -> Result<(), SpirvCrossError> {
+        static BASIC_SPV: &[u8] = include_bytes!("../basic.spv");
+
+        let mut compiler = Compiler::<targets::None>::new(Module::from_bytes(BASIC_SPV)?)?;
+        let words = compiler.spirv_words()?;
+        assert_eq!(BASIC_SPV, bytemuck::cast_slice::<u32, u8>(&words));
+
+        let ubo = compiler
+            .shader_resources()?
+            .all_resources()?
+            .uniform_buffers[0]
+            .id;
+        compiler.set_decoration(ubo, spirv::Decoration::Binding, Some(7u32))?;
+        assert_eq!(words, compiler.spirv_words()?);
+
+        Ok(())
+    }
+}
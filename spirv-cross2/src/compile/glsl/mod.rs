@@ -1,8 +1,9 @@
-use super::CommonOptions;
+use super::{CommonOptions, CommonOptionsOverrides, RawOptions};
 use crate::compile::sealed::ApplyCompilerOptions;
 use crate::error::ToContextError;
 use crate::handle::Handle;
 use crate::iter::impl_iterator;
+use crate::reflect::{BuiltinResourceType, DecorationValue, TypeInner};
 use crate::sealed::Sealed;
 use crate::targets::Glsl;
 use crate::{error, Compiler, CompilerStr, ContextRooted, PhantomCompiler};
@@ -14,14 +15,14 @@ use std::ops::Range;
 impl Sealed for CompilerOptions {}
 /// GLSL compiler options.
 #[non_exhaustive]
-#[derive(Debug, spirv_cross2_derive::CompilerOptions)]
+#[derive(Debug, Clone, spirv_cross2_derive::CompilerOptions)]
 pub struct CompilerOptions {
     /// Compile options common to GLSL, HLSL, and MSL.
     #[expand]
     pub common: CommonOptions,
 
     /// The GLSL version to output. The default is #version 450.
-    #[expand]
+    #[expand(Option<GlslVersion>)]
     pub version: GlslVersion,
 
     /// If true, Vulkan GLSL features are used instead of GL-compatible features.
@@ -33,7 +34,17 @@ pub struct CompilerOptions {
     /// The members of gl_PerVertex is determined by which built-ins are declared by the shader.
     /// This option is ignored in ES versions, as redeclaration in ES is not required, and it depends on a different extension
     /// (EXT_shader_io_blocks) which makes things a bit more fuzzy.
+    ///
+    /// For desktop GLSL targets older than version 410, this requires
+    /// the `GL_ARB_separate_shader_objects` extension.
     #[option(SPVC_COMPILER_OPTION_GLSL_SEPARATE_SHADER_OBJECTS, false)]
+    pub separate_shader_objects: bool,
+
+    /// Misspelled alias of [`CompilerOptions::separate_shader_objects`].
+    ///
+    /// If either option is true, gl_PerVertex is explicitly redeclared.
+    #[deprecated = "Use CompilerOptions::separate_shader_objects instead."]
+    #[local_option(false)]
     pub seperate_shader_objects: bool,
 
     /// For older desktop GLSL targets than version 420, the
@@ -41,6 +52,10 @@ pub struct CompilerOptions {
     /// layout(binding) on UBOs and samplers.
     /// If disabled on older targets, binding decorations will be stripped.
     ///
+    /// This option has no effect on desktop GLSL 420 and newer, which supports
+    /// layout(binding) natively. The extension is not available in ES,
+    /// so binding decorations are always stripped for ES versions older than 310.
+    ///
     /// The default is true.
     #[option(SPVC_COMPILER_OPTION_GLSL_ENABLE_420PACK_EXTENSION, true)]
     pub enable_420pack_extension: bool,
@@ -51,12 +66,17 @@ pub struct CompilerOptions {
     #[option(SPVC_COMPILER_OPTION_GLSL_SUPPORT_NONZERO_BASE_INSTANCE, true)]
     pub support_nonzero_base_instance: bool,
 
-    /// If true, sets the default float precision in ES targets to highp,
+    /// If true, sets the default float precision in ES fragment shaders to highp,
     /// otherwise the default is mediump.
+    ///
+    /// ES targets are selected with [`CompilerOptions::version`], which also
+    /// enables ES output. The default precision is emitted as `precision highp float;`
+    /// or `precision mediump float;` at the top of fragment shaders. Other stages
+    /// always default to highp, as required by ESSL.
     #[option(SPVC_COMPILER_OPTION_GLSL_ES_DEFAULT_FLOAT_PRECISION_HIGHP, false)]
     pub es_default_float_precision_highp: bool,
 
-    /// If false, sets the default float precision in ES targets to mediump,
+    /// If false, sets the default int precision in ES fragment shaders to mediump,
     /// otherwise the default is highp.
     #[option(SPVC_COMPILER_OPTION_GLSL_ES_DEFAULT_INT_PRECISION_HIGHP, true)]
     pub es_default_int_precision_highp: bool,
@@ -85,8 +105,24 @@ pub struct CompilerOptions {
     /// If non-zero, controls `layout(num_views = N) in;` in GL_OVR_multiview2.
     #[option(SPVC_COMPILER_OPTION_GLSL_OVR_MULTIVIEW_VIEW_COUNT, 0)]
     pub ovr_multiview_view_count: u32,
+
+    /// If true, the `Position` built-in output is decorated as `Invariant`,
+    /// emitting `invariant gl_Position;` so that positions computed by different
+    /// shaders with the same expressions are bit-identical.
+    ///
+    /// If false, the `Invariant` decoration declared in the SPIR-V module is kept as is.
+    #[local_option(false)]
+    pub emit_invariant_position: bool,
+
+    /// Options set by their raw SPIRV-Cross key with [`CompilerOptions::set_raw`].
+    ///
+    /// These are applied last, so that they take precedence over typed options.
+    #[expand(RawOptions)]
+    raw: RawOptions,
 }
 
+crate::compile::impl_raw_options!(CompilerOptions, CompilerOptionsOverrides);
+
 impl Sealed for GlslVersion {}
 
 /// GLSL language version.
@@ -181,6 +217,14 @@ impl ApplyCompilerOptions for GlslVersion {
 
         Ok(())
     }
+
+    type Overrides = Option<GlslVersion>;
+
+    fn merge(&mut self, overrides: &Option<GlslVersion>) {
+        if let Some(version) = overrides {
+            *self = *version;
+        }
+    }
 }
 
 impl Compiler<Glsl> {
@@ -201,6 +245,126 @@ impl Compiler<Glsl> {
         unsafe { sys::spvc_compiler_flatten_buffer_block(self.ptr.as_ptr(), block).ok(&*self) }
     }
 
+    /// Set the policy for how an extension may be used in the compiled output.
+    ///
+    /// * [`ExtensionPolicy::Require`] always enables the extension, as with [`Compiler::require_extension`].
+    /// * [`ExtensionPolicy::Forbid`] causes [`Compiler::compile`] to return
+    ///   [`SpirvCrossError::UnsupportedSpirv`](crate::SpirvCrossError::UnsupportedSpirv) naming the extension if the output would require it.
+    /// * [`ExtensionPolicy::Auto`] lets SPIRV-Cross decide whether the extension is needed.
+    ///
+    /// Once an extension is required, it can not be unrequired by setting the policy to `Auto`.
+    pub fn set_extension_policy<'str>(
+        &mut self,
+        extension: impl Into<CompilerStr<'str>>,
+        policy: ExtensionPolicy,
+    ) -> error::Result<()> {
+        let extension = extension.into();
+        self.forbidden_extensions
+            .retain(|forbidden| *forbidden != *extension);
+
+        match policy {
+            ExtensionPolicy::Require => self.require_extension(extension),
+            ExtensionPolicy::Forbid => {
+                self.forbidden_extensions.push(extension.to_string());
+                Ok(())
+            }
+            ExtensionPolicy::Auto => Ok(()),
+        }
+    }
+
+    /// Decorate the `Position` built-in output as `Invariant`, whether it is declared
+    /// as a variable or as a member of the `gl_PerVertex` block.
+    pub(crate) fn set_position_invariant(&mut self) -> error::Result<()> {
+        let positions: Vec<_> = self
+            .shader_resources()?
+            .builtin_resources_for_type(BuiltinResourceType::StageOutput)?
+            .filter(|output| output.builtin == spirv::BuiltIn::Position)
+            .map(|output| (output.resource.id, output.resource.base_type_id))
+            .collect();
+
+        for (variable, block) in positions {
+            if self
+                .decoration(variable, spirv::Decoration::BuiltIn)?
+                .is_some()
+            {
+                self.set_decoration(variable, spirv::Decoration::Invariant, Some(()))?;
+                continue;
+            }
+
+            let TypeInner::Struct(block_type) = self.type_description(block)?.inner else {
+                continue;
+            };
+
+            for index in 0..block_type.members.len() as u32 {
+                let is_position = matches!(
+                    self.member_decoration_by_handle(block, index, spirv::Decoration::BuiltIn)?,
+                    Some(DecorationValue::BuiltIn(spirv::BuiltIn::Position))
+                );
+
+                if is_position {
+                    self.set_member_decoration_by_handle(
+                        block,
+                        index,
+                        spirv::Decoration::Invariant,
+                        Some(()),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set the precision qualifier emitted for a resource in ES targets.
+    ///
+    /// SPIR-V can only express two precisions, so this sets or clears the `RelaxedPrecision`
+    /// decoration on the variable. The qualifier is only emitted if it differs from the default
+    /// precision set by [`CompilerOptions::es_default_float_precision_highp`] and
+    /// [`CompilerOptions::es_default_int_precision_highp`].
+    pub fn set_resource_precision(
+        &mut self,
+        variable: impl Into<Handle<VariableId>>,
+        precision: Precision,
+    ) -> error::Result<()> {
+        let variable = variable.into();
+        match precision {
+            Precision::Mediump => {
+                self.set_decoration(variable, spirv::Decoration::RelaxedPrecision, Some(()))
+            }
+            Precision::Highp => {
+                self.set_decoration(variable, spirv::Decoration::RelaxedPrecision, None::<()>)
+            }
+        }
+    }
+
+    /// Apply `setup` to the compilation instance, compile source code to the output target,
+    /// then restore the state of the compiler to what it was before `setup` was called.
+    ///
+    /// The following state is restored, so that one compiler instance can be reused
+    /// to compile several variants:
+    ///
+    /// * The module, including decorations such as binding remaps, names, the current entry point,
+    ///   combined image samplers, and any changes made to the module by compilation itself.
+    /// * The enabled and pinned interface variables.
+    /// * Header lines, required extensions and the extension policy.
+    /// * Buffer blocks flattened with [`Compiler<Glsl>::flatten_buffer_block`].
+    ///
+    /// This is only available for GLSL, because the HLSL and MSL backends keep resource bindings,
+    /// vertex attribute remaps and other state outside the module, which can not be restored.
+    ///
+    /// Handles to IDs created during `setup` must not be used after this returns.
+    /// The compiler is restored whether `setup` or compilation succeeds or fails.
+    ///
+    /// Unlike [`Compiler::compile`], the compiler instance is not consumed,
+    /// so only the compiled source code is returned.
+    pub fn compile_scoped(
+        &mut self,
+        setup: impl FnOnce(&mut Self) -> error::Result<()>,
+        options: &CompilerOptions,
+    ) -> error::Result<CompilerStr<'static>> {
+        self.compile_restoring(setup, options)
+    }
+
     /// Returns the list of required extensions in a GLSL shader.
     ///
     /// If called after compilation this will contain any other extensions that the compiler
@@ -218,6 +382,27 @@ impl Compiler<Glsl> {
     }
 }
 
+/// How an extension may be used in the compiled output, set with [`Compiler<Glsl>::set_extension_policy`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum ExtensionPolicy {
+    /// The extension is always enabled.
+    Require,
+    /// The extension must not be used. Compilation fails if it would be required.
+    Forbid,
+    /// The extension is enabled only if SPIRV-Cross determines it is needed.
+    #[default]
+    Auto,
+}
+
+/// The precision of a resource in ES targets, set with [`Compiler<Glsl>::set_resource_precision`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Precision {
+    /// The resource is declared `mediump`, corresponding to `RelaxedPrecision`.
+    Mediump,
+    /// The resource is declared `highp`.
+    Highp,
+}
+
 /// Iterator for required GLSL extensions, created by [`Compiler<Glsl>::required_extensions`].
 pub struct GlslExtensionsIter<'a>(
     // 'a is 'compiler.
@@ -246,10 +431,10 @@ impl_iterator!(GlslExtensionsIter<'c>: CompilerStr<'c> as and_then |s, index: us
 
 #[cfg(test)]
 mod test {
-    use crate::compile::glsl::CompilerOptions;
-    use spirv_cross_sys::spvc_compiler_create_compiler_options;
+    use crate::compile::glsl::{CompilerOptions, CompilerOptionsOverrides, GlslVersion};
+    use spirv_cross_sys::{spvc_compiler_create_compiler_options, spvc_compiler_option};
 
-    use crate::compile::CompilableTarget;
+    use crate::compile::{CompilableTarget, RawOptionValue};
     use crate::error::{SpirvCrossError, ToContextError};
     use crate::targets::Glsl;
     use crate::Compiler;
@@ -283,6 +468,49 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    pub fn raw_option_overrides_typed_option() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let compiler: Compiler<targets::Glsl> = Compiler::new(words)?;
+
+        let mut options = Glsl::options();
+        options.version = GlslVersion::Glsl450;
+        options.set_raw(
+            spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_VERSION,
+            RawOptionValue::Uint(330),
+        );
+
+        let artifact = compiler.compile(&options)?;
+        assert!(artifact.to_string().starts_with("#version 330"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn merge_raw_options() {
+        let mut options = CompilerOptions::default();
+        options.set_raw(
+            spvc_compiler_option::SPVC_COMPILER_OPTION_RELAX_NAN_CHECKS,
+            RawOptionValue::Bool(true),
+        );
+
+        let mut overrides = CompilerOptionsOverrides::default();
+        overrides.set_raw(
+            spvc_compiler_option::SPVC_COMPILER_OPTION_RELAX_NAN_CHECKS,
+            RawOptionValue::Bool(false),
+        );
+
+        options.merge(&overrides);
+        assert_eq!(
+            Some(RawOptionValue::Bool(false)),
+            options
+                .raw()
+                .get(spvc_compiler_option::SPVC_COMPILER_OPTION_RELAX_NAN_CHECKS)
+        );
+    }
+
     #[test]
     pub fn required_extensions() -> Result<(), SpirvCrossError> {
         let words = Vec::from(BASIC_SPV);
@@ -317,4 +545,317 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    pub fn compile_scoped_restores_state() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let mut compiler: Compiler<targets::Glsl> = Compiler::new(words)?;
+
+        let source = compiler.compile_scoped(
+            |compiler| {
+                compiler.add_header_line("// scoped header")?;
+                compiler.require_extension("GL_KHR_my_Extension")
+            },
+            &Glsl::options(),
+        )?;
+
+        assert!(source.contains("// scoped header"));
+        assert!(source.contains("GL_KHR_my_Extension"));
+        assert_eq!(0, compiler.required_extensions().count());
+
+        let artifact = compiler.compile(&Glsl::options())?;
+        assert!(!artifact.as_ref().contains("// scoped header"));
+        assert!(!artifact.as_ref().contains("GL_KHR_my_Extension"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn compile_scoped_binding_remaps() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let mut compiler: Compiler<targets::Glsl> = Compiler::new(words)?;
+        let ubo = compiler
+            .shader_resources()?
+            .all_resources()?
+            .uniform_buffers[0]
+            .id;
+
+        let first = compiler.compile_scoped(
+            |compiler| compiler.set_decoration(ubo, spirv::Decoration::Binding, Some(7u32)),
+            &Glsl::options(),
+        )?;
+        let second = compiler.compile_scoped(
+            |compiler| compiler.set_decoration(ubo, spirv::Decoration::Binding, Some(9u32)),
+            &Glsl::options(),
+        )?;
+
+        assert!(first.contains("binding = 7"));
+        assert!(second.contains("binding = 9"));
+        assert!(!second.contains("binding = 7"));
+
+        let artifact = compiler.compile(&Glsl::options())?;
+        assert!(!artifact.as_ref().contains("binding = 7"));
+        assert!(!artifact.as_ref().contains("binding = 9"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn compile_scoped_flattened_buffer_block() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let mut compiler: Compiler<targets::Glsl> = Compiler::new(words)?;
+        let ubo = compiler
+            .shader_resources()?
+            .all_resources()?
+            .uniform_buffers[0]
+            .id;
+
+        let mut options = Glsl::options();
+        options.version = GlslVersion::Glsl330;
+
+        let flattened =
+            compiler.compile_scoped(|compiler| compiler.flatten_buffer_block(ubo), &options)?;
+        assert!(flattened.contains("uniform vec4 UBO[4];"));
+
+        let artifact = compiler.compile(&options)?;
+        assert!(!artifact.as_ref().contains("uniform vec4 UBO[4];"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn compile_all_annotated() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let mut compiler: Compiler<targets::Glsl> = Compiler::new(words)?;
+        let source = compiler.compile_all_annotated(&Glsl::options())?;
+
+        assert!(source.starts_with("// entry: main (Fragment)\n#version"));
+        Ok(())
+    }
+
+    #[test]
+    pub fn to_encoded() -> Result<(), SpirvCrossError> {
+        use crate::compile::EncodingOptions;
+
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let compiler: Compiler<targets::Glsl> = Compiler::new(words)?;
+        let artifact = compiler.compile(&Glsl::options())?;
+
+        let encoded = artifact.to_encoded(EncodingOptions {
+            crlf: true,
+            bom: true,
+        });
+
+        let encoded = std::str::from_utf8(&encoded).expect("encoded source is valid UTF-8");
+        let encoded = encoded.strip_prefix('\u{FEFF}').expect("BOM was prepended");
+        assert_eq!(artifact.as_ref().replace('\n', "\r\n"), encoded);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn as_bytes() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let compiler: Compiler<targets::Glsl> = Compiler::new(words)?;
+        let artifact = compiler.compile(&Glsl::options())?;
+
+        let bytes = artifact.as_bytes();
+        assert_eq!(artifact.as_ref().as_bytes(), bytes);
+        assert_ne!(Some(&0), bytes.last());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn vertex_clipspace_opts() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Vertex %main "main" %position %in_position
+            OpDecorate %position BuiltIn Position
+            OpDecorate %in_position Location 0
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %float = OpTypeFloat 32
+            %v4float = OpTypeVector %float 4
+            %ptr_out = OpTypePointer Output %v4float
+            %ptr_in = OpTypePointer Input %v4float
+            %position = OpVariable %ptr_out Output
+            %in_position = OpVariable %ptr_in Input
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            %value = OpLoad %v4float %in_position
+            OpStore %position %value
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let mut options = Glsl::options();
+        assert!(!options.common.flip_vertex_y);
+        assert!(!options.common.fixup_clipspace);
+
+        let compiler: Compiler<targets::Glsl> = Compiler::new(Module::from_words(&words))?;
+        let source = compiler.compile(&options)?.to_string();
+        assert!(!source.contains("gl_Position.y = -gl_Position.y;"));
+        assert!(!source.contains("gl_Position.z = 2.0 * gl_Position.z - gl_Position.w;"));
+
+        options.common.flip_vertex_y = true;
+        options.common.fixup_clipspace = true;
+
+        let compiler: Compiler<targets::Glsl> = Compiler::new(Module::from_words(&words))?;
+        let source = compiler.compile(&options)?.to_string();
+        assert!(source.contains("gl_Position.y = -gl_Position.y;"));
+        assert!(source.contains("gl_Position.z = 2.0 * gl_Position.z - gl_Position.w;"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn separate_shader_objects() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Vertex %main "main" %position
+            OpDecorate %position BuiltIn Position
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %float = OpTypeFloat 32
+            %v4float = OpTypeVector %float 4
+            %float_0 = OpConstant %float 0
+            %zero = OpConstantComposite %v4float %float_0 %float_0 %float_0 %float_0
+            %ptr_out = OpTypePointer Output %v4float
+            %position = OpVariable %ptr_out Output
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpStore %position %zero
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let mut options = Glsl::options();
+        let compiler: Compiler<targets::Glsl> = Compiler::new(Module::from_words(&words))?;
+        let source = compiler.compile(&options)?.to_string();
+        assert!(!source.contains("out gl_PerVertex"));
+
+        options.separate_shader_objects = true;
+        let compiler: Compiler<targets::Glsl> = Compiler::new(Module::from_words(&words))?;
+        let source = compiler.compile(&options)?.to_string();
+        assert!(source.contains("out gl_PerVertex"));
+
+        // The misspelled option still enables separate shader objects.
+        let mut options = Glsl::options();
+        #[allow(deprecated)]
+        {
+            options.seperate_shader_objects = true;
+        }
+        let compiler: Compiler<targets::Glsl> = Compiler::new(Module::from_words(&words))?;
+        let source = compiler.compile(&options)?.to_string();
+        assert!(source.contains("out gl_PerVertex"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn es_default_precision() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+
+        let mut options = Glsl::options();
+        options.version = GlslVersion::Glsl300Es;
+        assert!(!options.es_default_float_precision_highp);
+        assert!(options.es_default_int_precision_highp);
+
+        let compiler: Compiler<targets::Glsl> =
+            Compiler::new(Module::from_words(bytemuck::cast_slice(&words)))?;
+        let source = compiler.compile(&options)?.to_string();
+        assert!(source.contains("precision mediump float;"));
+        assert!(source.contains("precision highp int;"));
+
+        options.es_default_float_precision_highp = true;
+        options.es_default_int_precision_highp = false;
+
+        let compiler: Compiler<targets::Glsl> =
+            Compiler::new(Module::from_words(bytemuck::cast_slice(&words)))?;
+        let source = compiler.compile(&options)?.to_string();
+        assert!(source.contains("precision highp float;"));
+        assert!(source.contains("precision mediump int;"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn emit_line_directives() -> Result<(), SpirvCrossError> {
+        const OP_STRING: u32 = 7;
+        const OP_LINE: u32 = 8;
+        const OP_SOURCE: u32 = 3;
+        const OP_LABEL: u32 = 248;
+
+        fn find_op(words: &[u32], opcode: u32) -> usize {
+            let mut index = 5;
+            while words[index] & 0xffff != opcode {
+                index += (words[index] >> 16) as usize;
+            }
+            index
+        }
+
+        let vec = Vec::from(BASIC_SPV);
+        let mut words: Vec<u32> = bytemuck::cast_slice(&vec).to_vec();
+
+        // Allocate an ID for the file name string.
+        let file_id = words[3];
+        words[3] += 1;
+
+        let mut file_name = [0u8; 12];
+        file_name[..9].copy_from_slice(b"test.glsl");
+        let file_name: [u32; 3] = bytemuck::cast(file_name);
+
+        let op_string = [(5 << 16) | OP_STRING, file_id]
+            .into_iter()
+            .chain(file_name);
+        let source = find_op(&words, OP_SOURCE);
+        words.splice(source..source, op_string);
+
+        let label = find_op(&words, OP_LABEL);
+        let op_line = [(4 << 16) | OP_LINE, file_id, 42, 0];
+        words.splice(label + 2..label + 2, op_line);
+
+        let mut options = Glsl::options();
+        options.common.emit_line_directives = true;
+
+        let compiler: Compiler<targets::Glsl> = Compiler::new(Module::from_words(&words))?;
+        let artifact = compiler.compile(&options)?;
+        assert!(artifact.as_ref().contains("#line 42 \"test.glsl\""));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn write_to_string() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let compiler: Compiler<targets::Glsl> = Compiler::new(words)?;
+        let artifact = compiler.compile(&Glsl::options())?;
+
+        let mut buffer = String::from("stale contents");
+        artifact.write_to_string(&mut buffer)?;
+        assert_eq!(artifact.as_ref(), buffer);
+
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use crate::compile::{CommonOptions, CompiledArtifact};
+use crate::compile::{CommonOptions, CommonOptionsOverrides, CompiledArtifact, RawOptions};
 use crate::targets::Hlsl;
 use crate::{error, Compiler};
 use bitflags::bitflags;
@@ -9,9 +9,11 @@ use spirv_cross_sys::{HlslResourceBinding, SpvBuiltIn, SpvExecutionModel};
 /// Specifies an HLSL root constant layout.
 pub use spirv_cross_sys::HlslRootConstants as RootConstants;
 
-use crate::error::ToContextError;
+use crate::error::{SpirvCrossError, ToContextError};
 use crate::handle::{Handle, VariableId};
+use crate::reflect::{ArrayDimension, DecorationValue, ResourceType, TypeInner};
 use crate::sealed::Sealed;
+use crate::spirv;
 use crate::string::CompilerStr;
 use crate::ContextRooted;
 use spirv_cross_sys as sys;
@@ -47,7 +49,7 @@ bitflags! {
 impl Sealed for CompilerOptions {}
 /// HLSL compiler options
 #[non_exhaustive]
-#[derive(Debug, spirv_cross2_derive::CompilerOptions)]
+#[derive(Debug, Clone, spirv_cross2_derive::CompilerOptions)]
 pub struct CompilerOptions {
     /// Compile options common to GLSL, HLSL, and MSL.
     #[expand]
@@ -115,8 +117,16 @@ pub struct CompilerOptions {
     /// whereas the type can be extended with an optional subtype, e.g. `structuredbuffer:int`.
     #[option(SPVC_COMPILER_OPTION_HLSL_PRESERVE_STRUCTURED_BUFFERS, false)]
     pub preserve_structured_buffers: bool,
+
+    /// Options set by their raw SPIRV-Cross key with [`CompilerOptions::set_raw`].
+    ///
+    /// These are applied last, so that they take precedence over typed options.
+    #[expand(RawOptions)]
+    raw: RawOptions,
 }
 
+crate::compile::impl_raw_options!(CompilerOptions, CompilerOptionsOverrides);
+
 /// HLSL Shader model.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[non_exhaustive]
@@ -303,11 +313,119 @@ impl Compiler<Hlsl> {
         }
     }
 
-    /// Compiles and remap vertex attribute at specific locations to a fixed semantic.
+    /// Place all descriptor resources of the current entry point into the given register space.
+    ///
+    /// This adds a resource binding for every resource decorated with a binding, which keeps
+    /// the register of the resource the same as its binding, but places it into `space` instead
+    /// of the space of its descriptor set.
+    ///
+    /// Resource bindings added with [`Compiler<Hlsl>::add_resource_binding`] **after** this call
+    /// take precedence, so this should be called before adding explicit resource bindings.
+    ///
+    /// Since the register of a resource is its binding, resources of the same register class
+    /// with the same binding in different descriptor sets would be placed in the same register.
+    /// In that case, no resource bindings are added and [`SpirvCrossError::InvalidArgument`] is returned.
+    ///
+    /// On SM 5.0 and lower, register spaces are ignored.
+    pub fn set_default_register_space(&mut self, space: u32) -> error::Result<()> {
+        use crate::reflect::HlslRegisterClass;
+        use std::collections::HashMap;
+
+        let stage = self.execution_model()?;
+        let resources = self.shader_resources()?.all_resources()?;
+
+        let mut bindings = Vec::new();
+        let mut registers: HashMap<(HlslRegisterClass, u32), u32> = HashMap::new();
+        for (ty, list) in [
+            (ResourceType::UniformBuffer, &resources.uniform_buffers),
+            (ResourceType::StorageBuffer, &resources.storage_buffers),
+            (ResourceType::StorageImage, &resources.storage_images),
+            (ResourceType::SampledImage, &resources.sampled_images),
+            (ResourceType::SeparateImage, &resources.separate_images),
+            (ResourceType::SeparateSamplers, &resources.separate_samplers),
+            (
+                ResourceType::AccelerationStructure,
+                &resources.acceleration_structures,
+            ),
+        ] {
+            for resource in list {
+                let Some(DecorationValue::Literal(binding)) =
+                    self.decoration(resource.id, spirv::Decoration::Binding)?
+                else {
+                    continue;
+                };
+
+                let set = self
+                    .decoration(resource.id, spirv::Decoration::DescriptorSet)?
+                    .and_then(|set| set.as_literal())
+                    .unwrap_or(0);
+
+                let classes: &[HlslRegisterClass] = match ty {
+                    ResourceType::UniformBuffer => &[HlslRegisterClass::ConstantBuffer],
+                    ResourceType::StorageBuffer => {
+                        // Read-only storage buffers are declared as SRVs, all others as UAVs.
+                        match self.buffer_block_decorations(resource.id)? {
+                            Some(decorations)
+                                if decorations.contains(&spirv::Decoration::NonWritable) =>
+                            {
+                                &[HlslRegisterClass::ShaderResource]
+                            }
+                            _ => &[HlslRegisterClass::UnorderedAccess],
+                        }
+                    }
+                    ResourceType::StorageImage => &[HlslRegisterClass::UnorderedAccess],
+                    ResourceType::SampledImage => &[
+                        HlslRegisterClass::ShaderResource,
+                        HlslRegisterClass::Sampler,
+                    ],
+                    ResourceType::SeparateSamplers => &[HlslRegisterClass::Sampler],
+                    _ => &[HlslRegisterClass::ShaderResource],
+                };
+
+                for &class in classes {
+                    match registers.insert((class, binding), set) {
+                        Some(other_set) if other_set != set => {
+                            return Err(SpirvCrossError::InvalidArgument(format!(
+                                "Resources in descriptor sets {other_set} and {set} would both be placed in the {class:?} register {binding}."
+                            )));
+                        }
+                        _ => {}
+                    }
+                }
+
+                bindings.push(ResourceBinding::from_qualified(set, binding));
+            }
+        }
+
+        for binding in bindings {
+            let register = Some(RegisterBinding {
+                register: binding.binding(),
+                space,
+            });
+
+            self.add_resource_binding(
+                stage,
+                binding,
+                &BindTarget {
+                    cbv: register,
+                    uav: register,
+                    srv: register,
+                    sampler: register,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Remap the vertex attribute at a specific location to a fixed semantic
+    /// when compiling.
     ///
     /// The default is `TEXCOORD#` where # denotes location.
     /// Matrices are unrolled to vectors with notation `${SEMANTIC}_#`, where # denotes row.
     /// `$SEMANTIC` is either `TEXCOORD#` or a semantic name specified here.
+    ///
+    /// The semantic name is copied by SPIRV-Cross, and does not need to outlive this call.
     pub fn remap_vertex_attribute<'str>(
         &mut self,
         location: u32,
@@ -330,11 +448,33 @@ impl Compiler<Hlsl> {
     /// Optionally specify a custom root constant layout.
     ///
     /// Push constants ranges will be split up according to the
-    /// layout specified.
+    /// layout specified. Each range covers the bytes from `start` up to,
+    /// but not including `end`.
+    ///
+    /// Returns an error if a range is inverted, or if any two ranges overlap.
     pub fn set_root_constant_layout(
         &mut self,
         constant_info: &[RootConstants],
     ) -> error::Result<()> {
+        for (index, constant) in constant_info.iter().enumerate() {
+            if constant.start > constant.end {
+                return Err(SpirvCrossError::InvalidArgument(format!(
+                    "root constant range {}..{} ends before it starts",
+                    constant.start, constant.end
+                )));
+            }
+
+            if let Some(other) = constant_info[..index]
+                .iter()
+                .find(|other| constant.start < other.end && other.start < constant.end)
+            {
+                return Err(SpirvCrossError::InvalidArgument(format!(
+                    "root constant range {}..{} overlaps with range {}..{}",
+                    constant.start, constant.end, other.start, other.end
+                )));
+            }
+        }
+
         unsafe {
             sys::spvc_compiler_hlsl_set_root_constants_layout(
                 self.ptr.as_ptr(),
@@ -381,12 +521,144 @@ impl Compiler<Hlsl> {
     /// so the calling application should declare explicit bindings on this ID before calling
     /// [`Compiler::compile`].
     pub fn remap_num_workgroups_builtin(&mut self) -> Option<Handle<VariableId>> {
+        self.invalidate_shader_resources();
         unsafe {
             let id = sys::spvc_compiler_hlsl_remap_num_workgroups_builtin(self.ptr.as_ptr());
             self.create_handle_if_not_zero(id)
         }
     }
 
+    /// Get the `b` register that the given element of a uniform buffer will be bound to.
+    ///
+    /// Arrays of UBOs are declared as `ConstantBuffer<T> name[N] : register(bN, spaceS)`,
+    /// which requires Shader Model 5.1 or higher. Each element of the array consumes
+    /// a consecutive register starting from the binding of the resource, so element `i`
+    /// is bound to register `b(N + i)`. For a UBO that is not an array, only element 0 is valid.
+    ///
+    /// The register is derived from the `Binding` decoration of the resource. Remappings
+    /// made with [`Compiler<Hlsl>::add_resource_binding`] are not taken into account; for a remapped
+    /// resource, the register of an element is the remapped `cbv` register plus the element index.
+    ///
+    /// Returns `None` if the resource has no `Binding` decoration, in which case the register
+    /// will be assigned automatically by the D3D compiler, or if the element is out of bounds
+    /// of the array.
+    ///
+    /// If the resource is not a uniform buffer, returns [`SpirvCrossError::InvalidArgument`](crate::SpirvCrossError::InvalidArgument).
+    /// This includes storage buffers declared with the legacy `BufferBlock` decoration,
+    /// which are bound to `u` or `t` registers.
+    pub fn cbuffer_register_for(
+        &self,
+        resource: impl Into<Handle<VariableId>>,
+        element: u32,
+    ) -> error::Result<Option<u32>> {
+        let resource = resource.into();
+        let ty = self.variable_type(resource)?;
+        let ty = self.type_description(ty)?;
+
+        let (count, mut base) = match &ty.inner {
+            TypeInner::Array {
+                base,
+                dimensions,
+                storage: spirv::StorageClass::Uniform,
+                ..
+            } => {
+                let mut count = 1u32;
+                for dim in dimensions {
+                    match dim {
+                        // Runtime arrays have no upper bound.
+                        ArrayDimension::Literal(0) => {
+                            count = u32::MAX;
+                            break;
+                        }
+                        ArrayDimension::Literal(len) => count = count.saturating_mul(*len),
+                        ArrayDimension::Constant(constant) => {
+                            let len = self.specialization_constant_value::<u32>(*constant)?;
+                            count = count.saturating_mul(len);
+                        }
+                    }
+                }
+                (count, *base)
+            }
+            TypeInner::Pointer {
+                base,
+                storage: spirv::StorageClass::Uniform,
+                ..
+            } => (1, *base),
+            _ => {
+                return Err(SpirvCrossError::InvalidArgument(String::from(
+                    "The resource is not a uniform buffer.",
+                )))
+            }
+        };
+
+        // Legacy storage buffers are declared in the Uniform storage class as well,
+        // but their block is decorated BufferBlock instead of Block.
+        loop {
+            match self.type_description(base)?.inner {
+                TypeInner::Pointer { base: inner, .. } | TypeInner::Array { base: inner, .. } => {
+                    base = inner
+                }
+                _ => break,
+            }
+        }
+
+        if self
+            .decoration(base, spirv::Decoration::BufferBlock)?
+            .is_some()
+        {
+            return Err(SpirvCrossError::InvalidArgument(String::from(
+                "The resource is a storage buffer, not a uniform buffer.",
+            )));
+        }
+
+        if element >= count {
+            return Ok(None);
+        }
+
+        let Some(DecorationValue::Literal(binding)) =
+            self.decoration(resource, spirv::Decoration::Binding)?
+        else {
+            return Ok(None);
+        };
+
+        Ok(binding.checked_add(element))
+    }
+
+    /// Remap the fragment output at the given location to the render target `SV_Target{target_index}`.
+    ///
+    /// Fragment outputs are assigned `SV_Target` semantics by their `Location` decoration, so this
+    /// rewrites the `Location` of every stage output declared at `location`. As remaps are applied immediately,
+    /// swapping two outputs requires setting the decorations by handle with [`Compiler::set_decoration`].
+    ///
+    /// If no stage output is declared at `location`, returns [`SpirvCrossError::InvalidArgument`].
+    pub fn remap_fragment_output(&mut self, location: u32, target_index: u32) -> error::Result<()> {
+        let mut outputs = Vec::new();
+        for output in self
+            .shader_resources()?
+            .resources_for_type(ResourceType::StageOutput)?
+        {
+            if let Some(DecorationValue::Literal(output_location)) =
+                self.decoration(output.id, spirv::Decoration::Location)?
+            {
+                if output_location == location {
+                    outputs.push(output.id);
+                }
+            }
+        }
+
+        if outputs.is_empty() {
+            return Err(SpirvCrossError::InvalidArgument(format!(
+                "No stage output is declared at location {location}."
+            )));
+        }
+
+        for output in outputs {
+            self.set_decoration(output, spirv::Decoration::Location, Some(target_index))?;
+        }
+
+        Ok(())
+    }
+
     /// Mask a stage output by location.
     ///
     /// If a shader output is active in this stage, but inactive in a subsequent stage,
@@ -451,7 +723,10 @@ impl CompiledArtifact<Hlsl> {
 
 #[cfg(test)]
 mod test {
-    use crate::compile::hlsl::CompilerOptions;
+    use crate::compile::hlsl::{
+        BindTarget, CompilerOptions, RegisterBinding, ResourceBinding, RootConstants,
+    };
+    use crate::compile::CompilableTarget;
     use spirv_cross_sys::spvc_compiler_create_compiler_options;
 
     use crate::compile::sealed::ApplyCompilerOptions;
@@ -491,4 +766,260 @@ mod test {
         // }
         Ok(())
     }
+
+    #[test]
+    pub fn hlsl_opts_merge() {
+        use crate::compile::hlsl::{CompilerOptionsOverrides, HlslShaderModel};
+
+        let mut base = CompilerOptions::default();
+        base.shader_model = HlslShaderModel::ShaderModel6_0;
+        base.common.flip_vertex_y = true;
+        base.common.relax_nan_checks = true;
+
+        let mut overrides = CompilerOptionsOverrides::default();
+        overrides.enable_16bit_types = Some(true);
+        overrides.common.fixup_clipspace = Some(true);
+        // Overrides can reset an option to its default value.
+        overrides.common.relax_nan_checks = Some(false);
+
+        base.merge(&overrides);
+
+        assert_eq!(HlslShaderModel::ShaderModel6_0, base.shader_model);
+        assert!(base.enable_16bit_types);
+        assert!(base.common.flip_vertex_y);
+        assert!(base.common.fixup_clipspace);
+        assert!(!base.common.relax_nan_checks);
+    }
+
+    #[test]
+    pub fn resource_binding() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let mut compiler: Compiler<targets::Hlsl> = Compiler::new(words)?;
+        compiler.add_resource_binding(
+            spirv::ExecutionModel::Fragment,
+            ResourceBinding::from_qualified(0, 0),
+            &BindTarget {
+                cbv: Some(RegisterBinding {
+                    register: 4,
+                    space: 0,
+                }),
+                uav: None,
+                srv: None,
+                sampler: None,
+            },
+        )?;
+
+        let artifact = compiler.compile(&targets::Hlsl::options())?;
+        assert!(artifact.to_string().contains("register(b4"));
+
+        assert!(artifact.is_resource_used(
+            spirv::ExecutionModel::Fragment,
+            ResourceBinding::from_qualified(0, 0)
+        ));
+        assert!(!artifact.is_resource_used(
+            spirv::ExecutionModel::Fragment,
+            ResourceBinding::from_qualified(1, 0)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn root_constant_layout_overlap() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let mut compiler: Compiler<targets::Hlsl> = Compiler::new(words)?;
+        compiler.set_root_constant_layout(&[
+            RootConstants {
+                start: 0,
+                end: 16,
+                binding: 0,
+                space: 0,
+            },
+            RootConstants {
+                start: 16,
+                end: 32,
+                binding: 1,
+                space: 0,
+            },
+        ])?;
+
+        let overlapping = compiler.set_root_constant_layout(&[
+            RootConstants {
+                start: 0,
+                end: 16,
+                binding: 0,
+                space: 0,
+            },
+            RootConstants {
+                start: 8,
+                end: 24,
+                binding: 1,
+                space: 0,
+            },
+        ]);
+        assert!(matches!(
+            overlapping,
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn remap_vertex_attribute() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Vertex %main "main" %in_position %out_position
+            OpName %in_position "in_position"
+            OpDecorate %in_position Location 0
+            OpDecorate %out_position BuiltIn Position
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %float = OpTypeFloat 32
+            %v4float = OpTypeVector %float 4
+            %_ptr_Input_v4float = OpTypePointer Input %v4float
+            %in_position = OpVariable %_ptr_Input_v4float Input
+            %_ptr_Output_v4float = OpTypePointer Output %v4float
+            %out_position = OpVariable %_ptr_Output_v4float Output
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            %value = OpLoad %v4float %in_position
+            OpStore %out_position %value
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let mut compiler: Compiler<targets::Hlsl> = Compiler::new(Module::from_words(&words))?;
+        {
+            let semantic = String::from("POSITION");
+            compiler.remap_vertex_attribute(0, semantic.as_str())?;
+        }
+
+        let artifact = compiler.compile(&targets::Hlsl::options())?;
+        assert!(artifact.as_ref().contains("in_position : POSITION;"));
+        assert!(!artifact.as_ref().contains("TEXCOORD0"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn cbuffer_register_for() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Fragment %main "main"
+            OpExecutionMode %main OriginUpperLeft
+            OpDecorate %ubo_block Block
+            OpMemberDecorate %ubo_block 0 Offset 0
+            OpDecorate %ubo DescriptorSet 0
+            OpDecorate %ubo Binding 2
+            OpDecorate %ssbo_block BufferBlock
+            OpMemberDecorate %ssbo_block 0 Offset 0
+            OpDecorate %ssbo DescriptorSet 0
+            OpDecorate %ssbo Binding 3
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %float = OpTypeFloat 32
+            %ubo_block = OpTypeStruct %float
+            %_ptr_Uniform_ubo_block = OpTypePointer Uniform %ubo_block
+            %ubo = OpVariable %_ptr_Uniform_ubo_block Uniform
+            %ssbo_block = OpTypeStruct %float
+            %_ptr_Uniform_ssbo_block = OpTypePointer Uniform %ssbo_block
+            %ssbo = OpVariable %_ptr_Uniform_ssbo_block Uniform
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::Hlsl> = Compiler::new(Module::from_words(&words))?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+
+        let ubo = resources.uniform_buffers[0].id;
+        assert_eq!(Some(2), compiler.cbuffer_register_for(ubo, 0)?);
+        assert_eq!(None, compiler.cbuffer_register_for(ubo, 1)?);
+
+        // BufferBlock storage buffers also use the Uniform storage class.
+        let ssbo = resources.storage_buffers[0].id;
+        assert!(matches!(
+            compiler.cbuffer_register_for(ssbo, 0),
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn set_default_register_space() -> Result<(), SpirvCrossError> {
+        let module = |image_class: &str| {
+            Module::from_spirv_asm(&format!(
+                r#"
+                OpCapability Shader
+                OpMemoryModel Logical GLSL450
+                OpEntryPoint Fragment %main "main" %color %ubo %other
+                OpExecutionMode %main OriginUpperLeft
+                OpDecorate %color Location 0
+                OpDecorate %block Block
+                OpMemberDecorate %block 0 Offset 0
+                OpDecorate %ubo DescriptorSet 0
+                OpDecorate %ubo Binding 0
+                OpDecorate %other DescriptorSet 1
+                OpDecorate %other Binding 0
+                %void = OpTypeVoid
+                %fn = OpTypeFunction %void
+                %float = OpTypeFloat 32
+                %v4float = OpTypeVector %float 4
+                %_ptr_Output_v4float = OpTypePointer Output %v4float
+                %color = OpVariable %_ptr_Output_v4float Output
+                %block = OpTypeStruct %v4float
+                %_ptr_Uniform_block = OpTypePointer Uniform %block
+                %ubo = OpVariable %_ptr_Uniform_block Uniform
+                %int = OpTypeInt 32 1
+                %int_0 = OpConstant %int 0
+                %_ptr_Uniform_v4float = OpTypePointer Uniform %v4float
+                {image_class}
+                %main = OpFunction %void None %fn
+                %entry = OpLabel
+                %member = OpAccessChain %_ptr_Uniform_v4float %ubo %int_0
+                %value = OpLoad %v4float %member
+                OpStore %color %value
+                OpReturn
+                OpFunctionEnd
+                "#
+            ))
+        };
+
+        // A second uniform buffer in set 1 would be placed in the same b0 register.
+        let words = module("%other = OpVariable %_ptr_Uniform_block Uniform")?;
+        let mut compiler: Compiler<targets::Hlsl> = Compiler::new(Module::from_words(&words))?;
+        assert!(matches!(
+            compiler.set_default_register_space(5),
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+
+        // A texture in set 1 is placed in the t0 register, which does not collide.
+        let words = module(
+            "%image = OpTypeImage %float 2D 0 0 0 1 Unknown
+            %_ptr_UniformConstant_image = OpTypePointer UniformConstant %image
+            %other = OpVariable %_ptr_UniformConstant_image UniformConstant",
+        )?;
+        let mut compiler: Compiler<targets::Hlsl> = Compiler::new(Module::from_words(&words))?;
+        compiler.set_default_register_space(5)?;
+
+        let mut options = targets::Hlsl::options();
+        options.shader_model = crate::compile::hlsl::HlslShaderModel::ShaderModel5_1;
+        let artifact = compiler.compile(&options)?;
+        assert!(artifact.as_ref().contains("register(b0, space5)"));
+
+        Ok(())
+    }
 }
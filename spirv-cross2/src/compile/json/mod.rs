@@ -0,0 +1,223 @@
+use crate::compile::CompiledArtifact;
+use crate::targets::Json;
+use crate::{error, SpirvCrossError};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The JSON reflection output of SPIRV-Cross, returned by [`CompiledArtifact<Json>::parse`].
+///
+/// Resources are grouped by kind in the same way as the JSON output. Types of
+/// resources and struct members are referred to by their GLSL name, or by the key of a struct
+/// type in [`JsonReflection::types`], i.e. `_8`.
+///
+/// Fields that are not emitted for a module are left empty.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonReflection {
+    /// The entry points of the module.
+    #[serde(rename = "entryPoints")]
+    pub entry_points: Vec<JsonEntryPoint>,
+    /// The struct types of the module, keyed by `_` followed by their type ID.
+    pub types: BTreeMap<String, JsonType>,
+    /// Stage input variables.
+    pub inputs: Vec<JsonResource>,
+    /// Stage output variables.
+    pub outputs: Vec<JsonResource>,
+    /// Combined image samplers.
+    pub textures: Vec<JsonResource>,
+    /// Separate images.
+    pub separate_images: Vec<JsonResource>,
+    /// Separate samplers.
+    pub separate_samplers: Vec<JsonResource>,
+    /// Storage images.
+    pub images: Vec<JsonResource>,
+    /// Storage buffers.
+    pub ssbos: Vec<JsonResource>,
+    /// Uniform buffers.
+    pub ubos: Vec<JsonResource>,
+    /// Push constant blocks.
+    pub push_constants: Vec<JsonResource>,
+    /// Subpass inputs.
+    pub subpass_inputs: Vec<JsonResource>,
+    /// Acceleration structures.
+    pub acceleration_structures: Vec<JsonResource>,
+    /// Specialization constants.
+    pub specialization_constants: Vec<JsonSpecializationConstant>,
+}
+
+/// An entry point in the JSON reflection output.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonEntryPoint {
+    /// The name of the entry point.
+    pub name: String,
+    /// The shader stage of the entry point, i.e. `vert`, `frag` or `comp`.
+    pub mode: String,
+    /// The workgroup size of a compute shader.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workgroup_size: Option<[u32; 3]>,
+    /// Whether each dimension of [`JsonEntryPoint::workgroup_size`] is the ID of a
+    /// specialization constant rather than a literal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workgroup_size_is_spec_constant_id: Option<[bool; 3]>,
+}
+
+/// A struct type in the JSON reflection output.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonType {
+    /// The name of the struct type.
+    pub name: String,
+    /// The members of the struct type.
+    pub members: Vec<JsonTypeMember>,
+}
+
+/// A member of a struct type in the JSON reflection output.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonTypeMember {
+    /// The name of the member.
+    pub name: String,
+    /// The type of the member.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The offset of the member in bytes, if the struct has an explicit layout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    /// The array dimensions of the member, if it is an array.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub array: Vec<u32>,
+    /// Whether each array dimension is a literal size rather than the ID of a specialization constant.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub array_size_is_literal: Vec<bool>,
+    /// The array stride of the member, if it is an array.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub array_stride: Option<u32>,
+    /// The matrix stride of the member, if it is a matrix.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matrix_stride: Option<u32>,
+    /// Whether the member is a row-major matrix.
+    pub row_major: bool,
+}
+
+/// A resource in the JSON reflection output.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonResource {
+    /// The type of the resource.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The name of the resource.
+    pub name: String,
+    /// The array dimensions of the resource, if it is an array.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub array: Vec<u32>,
+    /// Whether each array dimension is a literal size rather than the ID of a specialization constant.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub array_size_is_literal: Vec<bool>,
+    /// The declared size of a buffer block in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_size: Option<u32>,
+    /// The descriptor set of the resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set: Option<u32>,
+    /// The binding of the resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binding: Option<u32>,
+    /// The location of a stage input or output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<u32>,
+    /// The component of a stage input or output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component: Option<u32>,
+    /// The dual-source blending index of a stage output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<u32>,
+    /// The input attachment index of a subpass input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_attachment_index: Option<u32>,
+    /// The image format of a storage image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// Whether a storage buffer or storage image is read-only.
+    pub readonly: bool,
+    /// Whether a storage buffer or storage image is write-only.
+    pub writeonly: bool,
+}
+
+/// A specialization constant in the JSON reflection output.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonSpecializationConstant {
+    /// The name of the specialization constant.
+    pub name: String,
+    /// The `SpecId` of the specialization constant.
+    pub id: u32,
+    /// The type of the specialization constant.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The ID of the specialization constant in the module.
+    pub variable_id: u32,
+    /// The default value of the specialization constant.
+    pub default_value: Option<JsonConstantValue>,
+}
+
+/// The value of a scalar specialization constant in the JSON reflection output.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum JsonConstantValue {
+    /// A boolean value.
+    Bool(bool),
+    /// An unsigned integer value.
+    Uint(u64),
+    /// A signed integer value.
+    Int(i64),
+    /// A floating point value.
+    Float(f64),
+}
+
+impl CompiledArtifact<Json> {
+    /// Parse the JSON reflection output into a [`JsonReflection`].
+    ///
+    /// The raw JSON string is still available through [`Display`](std::fmt::Display)
+    /// and [`AsRef<str>`].
+    pub fn parse(&self) -> error::Result<JsonReflection> {
+        serde_json::from_str(self.as_ref()).map_err(|e| {
+            SpirvCrossError::InvalidOperation(format!("Failed to parse JSON reflection: {e}"))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::compile::CompilableTarget;
+    use crate::error::SpirvCrossError;
+    use crate::targets::Json;
+    use crate::Compiler;
+    use crate::{targets, Module};
+
+    static BASIC_SPV: &[u8] = include_bytes!("../../../basic.spv");
+
+    #[test]
+    pub fn parse() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let compiler: Compiler<targets::Json> = Compiler::new(words)?;
+        let artifact = compiler.compile(&Json::options())?;
+        let reflection = artifact.parse()?;
+
+        assert_eq!(1, reflection.entry_points.len());
+        assert_eq!("main", reflection.entry_points[0].name);
+        assert_eq!("frag", reflection.entry_points[0].mode);
+
+        assert_eq!(1, reflection.ubos.len());
+        assert_eq!(Some(0), reflection.ubos[0].binding);
+        assert!(reflection.types.contains_key(&reflection.ubos[0].ty));
+
+        assert_eq!(1, reflection.textures.len());
+        assert_eq!(Some(1), reflection.textures[0].binding);
+
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
 use crate::error::{Result, ToContextError};
 use crate::sealed::Sealed;
 use crate::targets::Target;
-use crate::{error, Compiler, CompilerStr, ContextRooted};
+use crate::{error, Compiler, CompilerStr, ContextRooted, SpirvCrossError};
 use spirv_cross_sys as sys;
+use std::ffi::CStr;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
@@ -21,10 +22,29 @@ pub mod hlsl;
 #[cfg_attr(docsrs, doc(cfg(feature = "msl")))]
 pub mod msl;
 
+/// Parsing of JSON reflection output.
+#[cfg(all(feature = "json", feature = "serde"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "json", feature = "serde"))))]
+pub mod json;
+
+#[cfg(any(feature = "glsl", feature = "hlsl", feature = "msl"))]
+mod multi;
+
+#[cfg(any(feature = "glsl", feature = "hlsl", feature = "msl"))]
+pub use multi::{MultiCompile, MultiOutput};
+
+use spirv_cross_sys::spvc_compiler_option;
+
 impl Sealed for CommonOptions {}
 
 /// Compile options common to all backends.
-#[derive(Debug, spirv_cross2_derive::CompilerOptions)]
+///
+/// SPIRV-Cross does not unroll loops, so there is no option to control unrolling.
+/// Loops in the output follow the control flow of the SPIR-V module, and unrolled loops
+/// in the compiled source were already unrolled by the compiler that produced the module.
+/// The `Unroll` and `DontUnroll` loop controls in the module are emitted as
+/// `[unroll]` and `[loop]` attributes by the HLSL backend.
+#[derive(Debug, Clone, spirv_cross2_derive::CompilerOptions)]
 pub struct CommonOptions {
     // common options
     /// Debug option to always emit temporary variables for all expressions.
@@ -77,6 +97,123 @@ pub struct CommonOptions {
     pub relax_nan_checks: bool,
 }
 
+/// Implement `set_raw` and `raw` for a set of compiler options with a private
+/// `raw: RawOptions` field, and `set_raw` for its overrides.
+macro_rules! impl_raw_options {
+    ($options:ty, $overrides:ty) => {
+        impl $options {
+            /// Set a SPIRV-Cross compiler option by its raw key, a
+            /// [`spvc_compiler_option`](spirv_cross_sys::spvc_compiler_option) of the
+            /// `spirv-cross-sys` crate.
+            ///
+            /// This is an advanced escape hatch for options that do not yet have a typed
+            /// wrapper in this crate. The key and value are passed to SPIRV-Cross unchecked,
+            /// and an invalid key or value type will only be reported when compiling.
+            /// Raw options take precedence over typed options with the same key.
+            ///
+            /// This API is not considered stable, and options may be removed from
+            /// SPIRV-Cross between releases.
+            pub fn set_raw(
+                &mut self,
+                key: ::spirv_cross_sys::spvc_compiler_option,
+                value: $crate::compile::RawOptionValue,
+            ) {
+                self.raw.set(key, value);
+            }
+
+            /// The options set by their raw key with `set_raw`.
+            pub fn raw(&self) -> &$crate::compile::RawOptions {
+                &self.raw
+            }
+        }
+
+        impl $overrides {
+            /// Override a SPIRV-Cross compiler option by its raw key.
+            ///
+            /// Raw overrides are merged into the raw options of the options they
+            /// are merged into, replacing any raw option with the same key.
+            pub fn set_raw(
+                &mut self,
+                key: ::spirv_cross_sys::spvc_compiler_option,
+                value: $crate::compile::RawOptionValue,
+            ) {
+                self.raw.set(key, value);
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "glsl", feature = "hlsl", feature = "msl"))]
+pub(crate) use impl_raw_options;
+
+/// The value of a compiler option set by its raw key with `set_raw`.
+///
+/// SPIRV-Cross only exposes boolean and integer options through its C API,
+/// so there is no string variant.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum RawOptionValue {
+    /// A boolean option.
+    Bool(bool),
+    /// An unsigned integer option.
+    Uint(u32),
+}
+
+/// Compiler options set by their raw SPIRV-Cross key.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct RawOptions(Vec<(spvc_compiler_option, RawOptionValue)>);
+
+impl RawOptions {
+    /// Set the option with the given key, replacing any previous value.
+    pub fn set(&mut self, key: spvc_compiler_option, value: RawOptionValue) {
+        if let Some(option) = self.0.iter_mut().find(|(k, _)| *k == key) {
+            option.1 = value;
+        } else {
+            self.0.push((key, value));
+        }
+    }
+
+    /// Get the value of the option with the given key, if it was set.
+    pub fn get(&self, key: spvc_compiler_option) -> Option<RawOptionValue> {
+        self.0
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| *value)
+    }
+}
+
+impl Sealed for RawOptions {}
+
+impl sealed::ApplyCompilerOptions for RawOptions {
+    unsafe fn apply(
+        &self,
+        options: sys::spvc_compiler_options,
+        root: impl ContextRooted + Copy,
+    ) -> error::Result<()> {
+        for (key, value) in &self.0 {
+            unsafe {
+                match *value {
+                    RawOptionValue::Bool(value) => {
+                        sys::spvc_compiler_options_set_bool(options, *key, value).ok(root)?
+                    }
+                    RawOptionValue::Uint(value) => {
+                        sys::spvc_compiler_options_set_uint(options, *key, value).ok(root)?
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    type Overrides = RawOptions;
+
+    fn merge(&mut self, overrides: &Self) {
+        for (key, value) in &overrides.0 {
+            self.set(*key, *value);
+        }
+    }
+}
+
 /// The output of a SPIRV-Cross compilation.
 ///
 /// [`CompiledArtifact`] implements [`Display`] with the
@@ -89,6 +226,73 @@ pub struct CompiledArtifact<T> {
     source: CompilerStr<'static>,
 }
 
+/// Options for encoding compiled source code with [`CompiledArtifact::to_encoded`].
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct EncodingOptions {
+    /// Use `\r\n` line endings instead of `\n`.
+    pub crlf: bool,
+    /// Prepend a UTF-8 byte order mark.
+    pub bom: bool,
+}
+
+impl<T> CompiledArtifact<T> {
+    /// Get the compiled source code as UTF-8 bytes, without copying.
+    ///
+    /// The returned slice does not include the trailing nul terminator.
+    pub fn as_bytes(&self) -> &[u8] {
+        let source: &str = self.source.as_ref();
+        source.as_bytes()
+    }
+
+    /// Write the compiled source code into a caller-provided buffer, replacing its contents.
+    ///
+    /// This reuses the allocation of `out`, to avoid allocating a new `String` for every
+    /// artifact when compiling many shaders.
+    ///
+    /// If the source code emitted by SPIRV-Cross was not valid UTF-8, returns
+    /// [`SpirvCrossError::InvalidString`] and leaves `out` empty.
+    pub fn write_to_string(&self, out: &mut String) -> error::Result<()> {
+        out.clear();
+
+        // Sources are only detached from the original C string if they had to be
+        // converted lossily.
+        if self.source.as_cstr().is_none() {
+            return Err(SpirvCrossError::InvalidString(self.source.to_string()));
+        }
+
+        out.push_str(self.source.as_ref());
+        Ok(())
+    }
+
+    /// Encode the compiled source code as UTF-8 bytes with the given line endings,
+    /// optionally prepended with a byte order mark.
+    ///
+    /// Existing `\r\n` line endings are normalized, so they will not be doubled.
+    pub fn to_encoded(&self, options: EncodingOptions) -> Vec<u8> {
+        const BOM: &[u8] = b"\xEF\xBB\xBF";
+        let source: &str = self.source.as_ref();
+        let newline: &[u8] = if options.crlf { b"\r\n" } else { b"\n" };
+
+        let mut encoded = Vec::with_capacity(source.len() + BOM.len());
+        if options.bom {
+            encoded.extend_from_slice(BOM);
+        }
+
+        let mut lines = source.split('\n').peekable();
+        while let Some(line) = lines.next() {
+            if lines.peek().is_none() {
+                encoded.extend_from_slice(line.as_bytes());
+                break;
+            }
+
+            encoded.extend_from_slice(line.strip_suffix('\r').unwrap_or(line).as_bytes());
+            encoded.extend_from_slice(newline);
+        }
+
+        encoded
+    }
+}
+
 impl<T> AsRef<str> for CompiledArtifact<T> {
     fn as_ref(&self) -> &str {
         self.source.as_ref()
@@ -123,6 +327,11 @@ impl<T: CompilableTarget> Compiler<T> {
     /// While this function is a more generic way of adding arbitrary text to the header
     /// of an output file, [`Compiler::require_extension`] should be used when adding extensions since it will
     /// avoid creating collisions with SPIRV-Cross generated extensions.
+    ///
+    /// SPIRV-Cross does not support changing the prefix of the identifiers it synthesizes.
+    /// Unnamed IDs and temporaries are always named `_` followed by the ID, i.e. `_25`,
+    /// and backend helpers are prefixed with `spv` or `SPIRV_Cross`. Identifiers declared in
+    /// header lines should avoid these patterns to prevent collisions with generated code.
     pub fn add_header_line<'str>(&mut self, line: impl Into<CompilerStr<'str>>) -> Result<()> {
         let line = line.into();
         let cstring = line.into_cstring_ptr()?;
@@ -153,6 +362,7 @@ impl<T: CompilableTarget> Compiler<T> {
                 .ok(&*self)?;
 
             options.apply(handle, &*self)?;
+            T::apply_local_options(options, handle, &*self)?;
 
             sys::spvc_compiler_install_compiler_options(self.ptr.as_ptr(), handle).ok(&*self)?;
 
@@ -160,24 +370,120 @@ impl<T: CompilableTarget> Compiler<T> {
         }
     }
 
-    /// Consume the compilation instance, and compile source code to the
-    /// output target.
-    pub fn compile(mut self, options: &T::Options) -> error::Result<CompiledArtifact<T>> {
+    /// Compile source code to the output target without consuming the compiler instance.
+    fn compile_source(&mut self, options: &T::Options) -> error::Result<CompilerStr<'static>> {
+        T::prepare(self, options)?;
         self.set_compiler_options(options)?;
 
+        // Compilation may add variables to the module.
+        self.invalidate_shader_resources();
+
         unsafe {
             let mut src = std::ptr::null();
-            sys::spvc_compiler_compile(self.ptr.as_ptr(), &mut src).ok(&self)?;
+            sys::spvc_compiler_compile(self.ptr.as_ptr(), &mut src).ok(&*self)?;
 
             // SAFETY: 'static is OK to return here
             // https://github.com/KhronosGroup/SPIRV-Cross/blob/6a1fb66eef1bdca14acf7d0a51a3f883499d79f0/spirv_cross_c.cpp#L1782
             let src = CompilerStr::from_ptr(src, self.ctx.drop_guard());
-            Ok(CompiledArtifact {
-                compiler: self,
-                source: src,
-            })
+
+            // Only GLSL compilers can have forbidden extensions.
+            if !self.forbidden_extensions.is_empty() {
+                let count = sys::spvc_compiler_get_num_required_extensions(self.ptr.as_ptr());
+                for index in 0..count {
+                    let extension =
+                        sys::spvc_compiler_get_required_extension(self.ptr.as_ptr(), index);
+                    if extension.is_null() {
+                        continue;
+                    }
+
+                    let extension = CStr::from_ptr(extension).to_string_lossy();
+                    if self
+                        .forbidden_extensions
+                        .iter()
+                        .any(|ext| *ext == extension)
+                    {
+                        return Err(SpirvCrossError::UnsupportedSpirv(format!(
+                            "The extension {extension} is required, but was forbidden by the extension policy"
+                        )));
+                    }
+                }
+            }
+
+            Ok(src)
         }
     }
+
+    /// Consume the compilation instance, and compile source code to the
+    /// output target.
+    pub fn compile(mut self, options: &T::Options) -> error::Result<CompiledArtifact<T>> {
+        let source = self.compile_source(options)?;
+        Ok(CompiledArtifact {
+            compiler: self,
+            source,
+        })
+    }
+
+    /// Apply `setup`, compile source code to the output target, then restore the module,
+    /// the enabled and pinned interface variables, and the state of the GLSL backend.
+    ///
+    /// State that is specific to the HLSL and MSL backends is not restored.
+    pub(crate) fn compile_restoring(
+        &mut self,
+        setup: impl FnOnce(&mut Self) -> error::Result<()>,
+        options: &T::Options,
+    ) -> error::Result<CompilerStr<'static>> {
+        let forbidden_extensions = self.forbidden_extensions.clone();
+        let pinned_variables = self.pinned_variables.clone();
+        let snapshot = unsafe {
+            let mut snapshot = std::ptr::null_mut();
+            sys::spvc_rs_compiler_snapshot_create(self.ptr.as_ptr(), &mut snapshot).ok(&*self)?;
+            snapshot
+        };
+
+        let source = setup(self).and_then(|_| self.compile_source(options));
+
+        self.forbidden_extensions = forbidden_extensions;
+        self.pinned_variables = pinned_variables;
+        self.invalidate_shader_resources();
+        unsafe {
+            let restored =
+                sys::spvc_rs_compiler_snapshot_restore(self.ptr.as_ptr(), snapshot).ok(&*self);
+            sys::spvc_rs_compiler_snapshot_free(snapshot);
+            restored?;
+        }
+
+        source
+    }
+
+    /// Compile every entry point in the module, and concatenate the compiled source
+    /// of each entry point after a `// entry: <name> (<stage>)` header.
+    ///
+    /// This is intended for debugging and inspection. The module is restored after each
+    /// entry point is compiled, so the current entry point is unchanged after this returns.
+    /// Backend-specific state set before this is called applies to every entry point.
+    pub fn compile_all_annotated(&mut self, options: &T::Options) -> error::Result<String> {
+        let entry_points: Vec<_> = self
+            .entry_points()?
+            .map(|entry_point| (entry_point.name.to_string(), entry_point.execution_model))
+            .collect();
+
+        let mut annotated = String::new();
+        for (name, model) in entry_points {
+            let source = self.compile_restoring(
+                |compiler| compiler.set_entry_point(name.as_str(), model),
+                options,
+            )?;
+
+            if !annotated.is_empty() {
+                annotated.push('\n');
+            }
+
+            annotated.push_str(&format!("// entry: {name} ({model:?})\n"));
+            annotated.push_str(source.as_ref());
+        }
+
+        Ok(annotated)
+    }
 }
 
 /// Marker trait for compiler options.
@@ -196,6 +502,12 @@ pub(crate) mod sealed {
             options: spvc_compiler_options,
             root: impl ContextRooted + Copy,
         ) -> error::Result<()>;
+
+        #[doc(hidden)]
+        type Overrides;
+
+        #[doc(hidden)]
+        fn merge(&mut self, overrides: &Self::Overrides);
     }
 }
 
@@ -223,7 +535,7 @@ impl Sealed for NoOptions {}
 /// No compilation options.
 ///
 /// Used for compiler backends that take no options.
-#[derive(Debug, Default, spirv_cross2_derive::CompilerOptions)]
+#[derive(Debug, Default, Clone, spirv_cross2_derive::CompilerOptions)]
 pub struct NoOptions;
 
 /// Marker trait for a compiler target that can have compiler outputs.
@@ -235,4 +547,25 @@ pub trait CompilableTarget: Target {
     fn options() -> Self::Options {
         Self::Options::default()
     }
+
+    /// Apply options that are handled by spirv-cross2 rather than SPIRV-Cross
+    /// to the compiler instance before compilation.
+    #[doc(hidden)]
+    fn prepare(_compiler: &mut Compiler<Self>, _options: &Self::Options) -> error::Result<()>
+    where
+        Self: Sized,
+    {
+        Ok(())
+    }
+
+    /// Apply options that are handled by spirv-cross2 to the SPIRV-Cross compiler options,
+    /// after all other options have been applied.
+    #[doc(hidden)]
+    unsafe fn apply_local_options(
+        _options: &Self::Options,
+        _handle: sys::spvc_compiler_options,
+        _root: impl ContextRooted + Copy,
+    ) -> error::Result<()> {
+        Ok(())
+    }
 }
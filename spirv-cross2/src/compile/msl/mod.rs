@@ -1,4 +1,4 @@
-use crate::compile::{CommonOptions, CompiledArtifact};
+use crate::compile::{CommonOptions, CommonOptionsOverrides, CompiledArtifact, RawOptions};
 use spirv_cross_sys as sys;
 
 /// An MSL `constexpr` inlined sampler.
@@ -56,7 +56,7 @@ use std::num::NonZeroU32;
 impl Sealed for CompilerOptions {}
 /// MSL compiler options
 #[non_exhaustive]
-#[derive(Debug, spirv_cross2_derive::CompilerOptions)]
+#[derive(Debug, Clone, spirv_cross2_derive::CompilerOptions)]
 pub struct CompilerOptions {
     /// Compile options common to GLSL, HLSL, and MSL.
     #[expand]
@@ -460,8 +460,16 @@ pub struct CompilerOptions {
         false
     )]
     pub force_fragment_with_side_effects_execution: bool,
+
+    /// Options set by their raw SPIRV-Cross key with [`CompilerOptions::set_raw`].
+    ///
+    /// These are applied last, so that they take precedence over typed options.
+    #[expand(RawOptions)]
+    raw: RawOptions,
 }
 
+crate::compile::impl_raw_options!(CompilerOptions, CompilerOptionsOverrides);
+
 /// The version of Metal Shading Language to compile to.
 ///
 /// Defaults to MSL 1.2.
@@ -532,7 +540,7 @@ impl From<(u32, u32, u32)> for MslVersion {
 ///
 /// Tier capabilities based on recommendations from Apple engineering.
 #[repr(u32)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum ArgumentBuffersTier {
     /// Tier1 supports writable images on macOS, but not on iOS.
     Tier1 = 0,
@@ -542,7 +550,7 @@ pub enum ArgumentBuffersTier {
 
 /// The platform that the Metal runtime will be on.
 #[repr(u32)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum MetalPlatform {
     #[allow(non_camel_case_types)]
     /// iOS (mobile and iPad)
@@ -553,7 +561,7 @@ pub enum MetalPlatform {
 
 /// The type of index in the index buffer.
 #[repr(u32)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum IndexType {
     /// No index
     None = 0,
@@ -745,6 +753,10 @@ impl ShaderInterfaceVariable {
 /// MSL specific APIs.
 impl Compiler<Msl> {
     /// Get whether the vertex shader requires rasterization to be disabled.
+    ///
+    /// This is only known after compilation, and always returns `false` before
+    /// the shader has been compiled.
+    #[deprecated = "Use CompiledArtifact<Msl>::is_rasterization_disabled instead."]
     pub fn is_rasterization_disabled(&self) -> bool {
         unsafe { sys::spvc_compiler_msl_is_rasterization_disabled(self.ptr.as_ptr()) }
     }
@@ -836,6 +848,8 @@ impl Compiler<Msl> {
 
     /// When using MSL argument buffers, we can force "classic" MSL 1.0 binding schemes for certain descriptor sets.
     /// This corresponds to VK_KHR_push_descriptor in Vulkan.
+    ///
+    /// This only has any effect if [`CompilerOptions::argument_buffers`] is enabled.
     pub fn add_discrete_descriptor_set(&mut self, desc_set: u32) -> error::Result<()> {
         unsafe {
             sys::spvc_compiler_msl_add_discrete_descriptor_set(self.ptr.as_ptr(), desc_set)
@@ -1080,6 +1094,18 @@ pub enum AutomaticResourceBindingTier {
 }
 
 impl CompiledArtifact<Msl> {
+    /// Get whether the vertex shader requires rasterization to be disabled.
+    ///
+    /// This is the case for vertex or tessellation evaluation shaders that
+    /// have their outputs captured to a buffer, or that do not write any
+    /// outputs that a fragment stage could consume. If so, `rasterizationEnabled`
+    /// should be set to `false` on the Metal render pipeline.
+    ///
+    /// This is always `false` for other stages.
+    pub fn is_rasterization_disabled(&self) -> bool {
+        unsafe { sys::spvc_compiler_msl_is_rasterization_disabled(self.compiler.ptr.as_ptr()) }
+    }
+
     /// Returns whether the set/binding combination provided in [`Compiler<Msl>::add_resource_binding`]
     /// was used.
     pub fn is_resource_used(&self, model: spirv::ExecutionModel, binding: ResourceBinding) -> bool {
@@ -1167,7 +1193,10 @@ impl CompiledArtifact<Msl> {
 
 #[cfg(test)]
 mod test {
-    use crate::compile::msl::CompilerOptions;
+    use crate::compile::msl::{
+        AutomaticResourceBindingTier, BindTarget, CompilerOptions, MslVersion, ResourceBinding,
+    };
+    use crate::compile::CompilableTarget;
     use spirv_cross_sys::spvc_compiler_create_compiler_options;
 
     use crate::compile::sealed::ApplyCompilerOptions;
@@ -1207,4 +1236,134 @@ mod test {
         // }
         Ok(())
     }
+
+    #[test]
+    pub fn resource_binding() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let mut compiler: Compiler<targets::Msl> = Compiler::new(words)?;
+        compiler.add_resource_binding(
+            spirv::ExecutionModel::Fragment,
+            ResourceBinding::from_qualified(0, 1),
+            &BindTarget {
+                buffer: 0,
+                texture: 3,
+                sampler: 2,
+                count: None,
+            },
+        )?;
+
+        let artifact = compiler.compile(&targets::Msl::options())?;
+        assert!(artifact.to_string().contains("[[texture(3)]]"));
+        assert!(artifact.to_string().contains("[[sampler(2)]]"));
+
+        assert!(artifact.is_resource_used(
+            spirv::ExecutionModel::Fragment,
+            ResourceBinding::from_qualified(0, 1)
+        ));
+        assert!(!artifact.is_resource_used(
+            spirv::ExecutionModel::Fragment,
+            ResourceBinding::from_qualified(1, 0)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn cleansed_entry_point_name() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let compiler: Compiler<targets::Msl> = Compiler::new(words)?;
+        let artifact = compiler.compile(&targets::Msl::options())?;
+
+        // main is reserved in MSL, so the entry point is renamed on compilation.
+        let name = artifact
+            .cleansed_entry_point_name("main", spirv::ExecutionModel::Fragment)?
+            .expect("entry point exists");
+
+        assert_ne!("main", name.as_ref());
+        assert!(artifact.to_string().contains(name.as_ref()));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn argument_buffers() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+
+        let mut options = targets::Msl::options();
+        options.version = MslVersion::from((2, 0));
+        options.argument_buffers = true;
+
+        let compiler: Compiler<targets::Msl> =
+            Compiler::new(Module::from_words(bytemuck::cast_slice(&words)))?;
+        let artifact = compiler.compile(&options)?;
+        assert!(artifact.to_string().contains("spvDescriptorSetBuffer0"));
+
+        let mut compiler: Compiler<targets::Msl> =
+            Compiler::new(Module::from_words(bytemuck::cast_slice(&words)))?;
+        compiler.add_discrete_descriptor_set(0)?;
+        compiler.set_argument_buffer_device_address_space(1, true)?;
+        let artifact = compiler.compile(&options)?;
+        assert!(!artifact.to_string().contains("spvDescriptorSetBuffer0"));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn automatic_resource_binding() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let compiler: Compiler<targets::Msl> = Compiler::new(words)?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+        let sampled_image = resources.sampled_images[0].id;
+
+        let artifact = compiler.compile(&targets::Msl::options())?;
+
+        // Combined image samplers are assigned both a texture and a sampler slot.
+        assert!(artifact
+            .automatic_resource_binding(sampled_image, AutomaticResourceBindingTier::Primary)?
+            .is_some());
+        assert!(artifact
+            .automatic_resource_binding(sampled_image, AutomaticResourceBindingTier::Secondary)?
+            .is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn is_rasterization_disabled() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let compiler: Compiler<targets::Msl> = Compiler::new(words)?;
+        let artifact = compiler.compile(&targets::Msl::options())?;
+
+        // basic.spv is a fragment shader.
+        assert!(!artifact.is_rasterization_disabled());
+
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Vertex %main "main"
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        // Vertex shaders without any stage output can not be rasterized.
+        let compiler: Compiler<targets::Msl> = Compiler::new(Module::from_words(&words))?;
+        let artifact = compiler.compile(&targets::Msl::options())?;
+        assert!(artifact.is_rasterization_disabled());
+
+        Ok(())
+    }
 }
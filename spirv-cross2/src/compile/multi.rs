@@ -0,0 +1,197 @@
+use crate::cell::CrossAllocationCell;
+use crate::reflect::OwnedReflection;
+use crate::{error, targets, Compiler, Module};
+
+/// A function applied to the module before it is compiled to every target,
+/// set with [`MultiCompile::setup`].
+type SetupFn<'a> = Box<dyn FnOnce(&mut Compiler<targets::None>) -> error::Result<()> + 'a>;
+
+/// Compile a single SPIR-V module to multiple targets at once.
+///
+/// The module is parsed only once. Any mutations made with [`MultiCompile::setup`]
+/// are made to the parsed module before it is copied to the compiler instance for each target,
+/// so that they are applied consistently to every output.
+///
+/// ```
+/// # use spirv_cross2::compile::MultiCompile;
+/// # use spirv_cross2::compile::CompilableTarget;
+/// # use spirv_cross2::targets::{Glsl, Hlsl, Msl};
+/// # use spirv_cross2::{Module, SpirvCrossError};
+/// fn compile_all(words: &[u32]) -> Result<(), SpirvCrossError> {
+///     let output = MultiCompile::new(Module::from_words(words))
+///         .glsl(Glsl::options())
+///         .hlsl(Hlsl::options())
+///         .msl(Msl::options())
+///         .run()?;
+///
+///     println!("{}", output.glsl.unwrap_or_default());
+///     Ok(())
+/// }
+/// ```
+pub struct MultiCompile<'a> {
+    module: Module<'a>,
+    setup: Option<SetupFn<'a>>,
+    #[cfg(feature = "glsl")]
+    glsl: Option<super::glsl::CompilerOptions>,
+    #[cfg(feature = "hlsl")]
+    hlsl: Option<super::hlsl::CompilerOptions>,
+    #[cfg(feature = "msl")]
+    msl: Option<super::msl::CompilerOptions>,
+}
+
+/// The output of [`MultiCompile::run`].
+///
+/// Outputs are `None` for targets that were not requested.
+#[derive(Debug)]
+pub struct MultiOutput {
+    /// The compiled GLSL source.
+    #[cfg(feature = "glsl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "glsl")))]
+    pub glsl: Option<String>,
+    /// The compiled HLSL source.
+    #[cfg(feature = "hlsl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hlsl")))]
+    pub hlsl: Option<String>,
+    /// The compiled MSL source.
+    #[cfg(feature = "msl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "msl")))]
+    pub msl: Option<String>,
+    /// Reflection of the module after setup, shared by all targets.
+    ///
+    /// The compiler instance the handles in the reflection data are tagged with
+    /// is dropped once all targets are compiled.
+    pub reflection: OwnedReflection,
+}
+
+impl<'a> MultiCompile<'a> {
+    /// Create a new multi-target compilation for the SPIR-V module.
+    ///
+    /// No targets are compiled unless requested.
+    pub fn new(module: Module<'a>) -> Self {
+        Self {
+            module,
+            setup: None,
+            #[cfg(feature = "glsl")]
+            glsl: None,
+            #[cfg(feature = "hlsl")]
+            hlsl: None,
+            #[cfg(feature = "msl")]
+            msl: None,
+        }
+    }
+
+    /// Set a function to mutate the module, i.e. remap decorations,
+    /// before it is compiled to any target.
+    pub fn setup(
+        mut self,
+        setup: impl FnOnce(&mut Compiler<targets::None>) -> error::Result<()> + 'a,
+    ) -> Self {
+        self.setup = Some(Box::new(setup));
+        self
+    }
+
+    /// Compile the module to GLSL with the given options.
+    #[cfg(feature = "glsl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "glsl")))]
+    pub fn glsl(mut self, options: super::glsl::CompilerOptions) -> Self {
+        self.glsl = Some(options);
+        self
+    }
+
+    /// Compile the module to HLSL with the given options.
+    #[cfg(feature = "hlsl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hlsl")))]
+    pub fn hlsl(mut self, options: super::hlsl::CompilerOptions) -> Self {
+        self.hlsl = Some(options);
+        self
+    }
+
+    /// Compile the module to MSL with the given options.
+    #[cfg(feature = "msl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "msl")))]
+    pub fn msl(mut self, options: super::msl::CompilerOptions) -> Self {
+        self.msl = Some(options);
+        self
+    }
+
+    /// Parse the module, apply the setup function, and compile to all requested targets.
+    pub fn run(self) -> error::Result<MultiOutput> {
+        let ctx = CrossAllocationCell::new()?;
+
+        // SAFETY: all compiler instances sharing the context are used sequentially,
+        // and dropped before returning.
+        let mut module: Compiler<targets::None> =
+            unsafe { ctx.share() }.into_compiler(self.module)?;
+
+        if let Some(setup) = self.setup {
+            setup(&mut module)?;
+        }
+
+        let reflection = module.reflect_owned()?;
+
+        Ok(MultiOutput {
+            #[cfg(feature = "glsl")]
+            glsl: self
+                .glsl
+                .map(|options| {
+                    unsafe { ctx.share() }
+                        .copy_compiler::<targets::Glsl, _>(&module)?
+                        .compile(&options)
+                })
+                .transpose()?
+                .map(|artifact| artifact.to_string()),
+            #[cfg(feature = "hlsl")]
+            hlsl: self
+                .hlsl
+                .map(|options| {
+                    unsafe { ctx.share() }
+                        .copy_compiler::<targets::Hlsl, _>(&module)?
+                        .compile(&options)
+                })
+                .transpose()?
+                .map(|artifact| artifact.to_string()),
+            #[cfg(feature = "msl")]
+            msl: self
+                .msl
+                .map(|options| {
+                    unsafe { ctx.share() }
+                        .copy_compiler::<targets::Msl, _>(&module)?
+                        .compile(&options)
+                })
+                .transpose()?
+                .map(|artifact| artifact.to_string()),
+            reflection,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "glsl"))]
+mod test {
+    use crate::compile::{CompilableTarget, MultiCompile};
+    use crate::error::SpirvCrossError;
+    use crate::targets::Glsl;
+    use crate::Module;
+
+    static BASIC_SPV: &[u8] = include_bytes!("../../basic.spv");
+
+    #[test]
+    pub fn multi_compile_setup() -> Result<(), SpirvCrossError> {
+        let words = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&words));
+
+        let output = MultiCompile::new(words)
+            .setup(|compiler| {
+                let ubo = compiler
+                    .shader_resources()?
+                    .all_resources()?
+                    .uniform_buffers[0]
+                    .id;
+                compiler.set_name(ubo, "renamed_ubo")
+            })
+            .glsl(Glsl::options())
+            .run()?;
+
+        assert!(output.glsl.unwrap().contains("renamed_ubo"));
+        Ok(())
+    }
+}
@@ -14,6 +14,9 @@ pub enum SpirvCrossError {
     #[error("The SPIR-V is invalid: {0}.")]
     /// The SPIR-V is invalid.
     InvalidSpirv(String),
+    #[error("The SPIR-V module is invalid: {0}.")]
+    /// The bytes of the SPIR-V module could not be read as SPIR-V words.
+    InvalidModule(String),
     #[error("The SPIR-V operation is unsupported: {0}.")]
     /// The SPIR-V operation is invalid.
     UnsupportedSpirv(String),
@@ -57,9 +60,16 @@ pub enum SpirvCrossError {
     #[error("An unexpected enum value was found.")]
     /// An unexpected enum value was found.
     InvalidEnum,
+    #[error("Conversion from another shader representation failed: {0}.")]
+    /// Conversion from another shader representation into SPIR-V failed.
+    Interop(String),
 }
 
 pub(crate) trait ToContextError {
+    /// Convert the result of a SPIRV-Cross call into a [`Result`].
+    ///
+    /// On failure, the error carries the last error message reported by SPIRV-Cross
+    /// for the context, verbatim.
     fn ok(self, context: impl ContextRooted) -> Result<()>;
 }
 
@@ -66,6 +66,43 @@ impl<T: Id> Handle<T> {
     }
 }
 
+/// Handles are serialized as their `u32` ID part only.
+///
+/// Deserialized handles are detached from any compiler instance, and will be rejected
+/// by every reflection query. To use a deserialized ID with a live compiler, it must be
+/// re-validated and forged with [`Compiler::create_handle`].
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<T: Id> serde::Serialize for Handle<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.id())
+    }
+}
+
+#[cfg(feature = "serde")]
+macro_rules! impl_deserialize_handle {
+    ($($id:ident),*) => {
+        $(
+            #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+            impl<'de> serde::Deserialize<'de> for Handle<$id> {
+                fn deserialize<D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    let id = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+                    Ok(Handle {
+                        id: $id(spirv_cross_sys::SpvId(id)),
+                        // A dangling tag never compares equal to a live compiler instance.
+                        tag: PointerOnlyForComparison(NonNull::dangling()),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "serde")]
+impl_deserialize_handle!(TypeId, VariableId, ConstantId);
+
 /// Trait for SPIRV-Cross ID types.
 pub trait Id: Sealed + Debug + Send + Sync + 'static {
     /// Return the `u32` part of the Id.
@@ -84,6 +84,41 @@
 //! spirv-cross2 = { features = ["f16", "gfx-maths-types", "glam-types"] }
 //! ```
 //!
+//! ### naga interop
+//! The `naga` feature enables [`Module::from_naga`] to write a [naga](https://crates.io/crates/naga)
+//! module into SPIR-V with naga's SPIR-V backend, so it can be cross-compiled with SPIRV-Cross.
+//!
+//! ```toml
+//! [dependencies]
+//! spirv-cross2 = { features = ["naga"] }
+//! ```
+//!
+//! ### Vulkan descriptor set layouts
+//! The `vulkan` feature enables [`reflect::vulkan::descriptor_set_layouts`] to describe the
+//! descriptor set layouts used by a shader, without depending on any Vulkan bindings.
+//!
+//! ```toml
+//! [dependencies]
+//! spirv-cross2 = { features = ["vulkan"] }
+//! ```
+//!
+//! ### Serialization
+//! The `serde` feature implements `Serialize` and `Deserialize` for plain-data reflection types
+//! such as [`Resource`](reflect::Resource), [`ResourceType`](reflect::ResourceType), and
+//! [`CombinedImageSampler`](reflect::CombinedImageSampler).
+//!
+//! Handles are serialized as their `u32` ID part only. Deserialized handles are detached from any
+//! compiler instance, and can not be passed back into reflection queries until the ID is
+//! re-validated against a live compiler and forged with [`Compiler::create_handle`].
+//!
+//! If the `json` feature is also enabled, the output of the JSON backend can be parsed into
+//! a `JsonReflection` with `CompiledArtifact<Json>::parse`.
+//!
+//! ```toml
+//! [dependencies]
+//! spirv-cross2 = { features = ["serde"] }
+//! ```
+//!
 //! ## Usage
 //! Here is an example of using the API to do some reflection and compile to GLSL.
 //!
@@ -130,11 +165,14 @@
 //! }
 //! ```
 //!
-use spirv_cross_sys::{spvc_compiler_s, SpvId};
+use spirv_cross_sys::{spvc_compiler_s, spvc_resources_s, SpvId, VariableId};
 
 use crate::cell::{AllocationDropGuard, CrossAllocationCell};
+use crate::error::ToContextError;
 use crate::sealed::{ContextRooted, Sealed};
 use crate::targets::Target;
+use std::borrow::Cow;
+use std::cell::Cell;
 use std::marker::PhantomData;
 use std::ptr::NonNull;
 
@@ -172,6 +210,7 @@ pub mod spirv {
     pub use spirv::ExecutionModel;
     pub use spirv::FPRoundingMode;
     pub use spirv::ImageFormat;
+    pub use spirv::SourceLanguage;
     pub use spirv::StorageClass;
 }
 
@@ -190,12 +229,124 @@ pub use crate::error::SpirvCrossError;
 pub use crate::string::CompilerStr;
 
 /// A SPIR-V Module represented as SPIR-V words.
-pub struct Module<'a>(&'a [SpvId]);
+pub struct Module<'a>(Cow<'a, [SpvId]>);
 
 impl<'a> Module<'a> {
     /// Create a new `Module` from SPIR-V words.
     pub fn from_words(words: &'a [u32]) -> Self {
-        Module(bytemuck::must_cast_slice(words))
+        Module(Cow::Borrowed(bytemuck::must_cast_slice(words)))
+    }
+
+    /// Create a new `Module` from the bytes of a SPIR-V binary, i.e. a `.spv` file.
+    ///
+    /// The byte order of the module is detected from the SPIR-V magic number.
+    /// The bytes are borrowed if they are already aligned words in native byte order,
+    /// otherwise they are copied, and byte-swapped if necessary.
+    ///
+    /// If the length of the bytes is not a multiple of four, or the bytes do not start
+    /// with the SPIR-V magic number, returns [`SpirvCrossError::InvalidModule`].
+    pub fn from_bytes(bytes: &'a [u8]) -> error::Result<Self> {
+        if bytes.len() % 4 != 0 {
+            return Err(SpirvCrossError::InvalidModule(format!(
+                "length {} is not a multiple of four",
+                bytes.len()
+            )));
+        }
+
+        let Some(magic) = bytes.get(..4) else {
+            return Err(SpirvCrossError::InvalidModule(String::from(
+                "missing SPIR-V magic number",
+            )));
+        };
+
+        let magic = u32::from_ne_bytes([magic[0], magic[1], magic[2], magic[3]]);
+        let swap = if magic == cell::SPIRV_MAGIC {
+            false
+        } else if magic.swap_bytes() == cell::SPIRV_MAGIC {
+            true
+        } else {
+            return Err(SpirvCrossError::InvalidModule(String::from(
+                "missing SPIR-V magic number",
+            )));
+        };
+
+        if !swap {
+            if let Ok(words) = bytemuck::try_cast_slice(bytes) {
+                return Ok(Module(Cow::Borrowed(words)));
+            }
+        }
+
+        let words = bytes
+            .chunks_exact(4)
+            .map(|word| {
+                let word = u32::from_ne_bytes([word[0], word[1], word[2], word[3]]);
+                SpvId(if swap { word.swap_bytes() } else { word })
+            })
+            .collect();
+
+        Ok(Module(Cow::Owned(words)))
+    }
+
+    /// Get the source language and its version, as declared by the `OpSource`
+    /// instruction of the module.
+    ///
+    /// Returns `None` if the module has no `OpSource` instruction,
+    /// or if the source language is not recognized.
+    pub fn source_language(&self) -> Option<(spirv::SourceLanguage, u32)> {
+        const OP_SOURCE: u32 = 3;
+
+        let words = &*self.0;
+        let swap = words.first()?.0 != cell::SPIRV_MAGIC;
+        let word = |index: usize| {
+            words
+                .get(index)
+                .map(|word| if swap { word.0.swap_bytes() } else { word.0 })
+        };
+
+        // Skip the header.
+        let mut index = 5;
+        while let Some(instruction) = word(index) {
+            let count = (instruction >> 16) as usize;
+            if instruction & 0xffff == OP_SOURCE && count >= 3 {
+                let language = spirv::SourceLanguage::from_u32(word(index + 1)?)?;
+                return Some((language, word(index + 2)?));
+            }
+
+            if count == 0 {
+                return None;
+            }
+            index += count;
+        }
+
+        None
+    }
+
+    /// Assemble SPIR-V assembly text into SPIR-V words, so that tests can declare
+    /// small shaders inline.
+    #[cfg(test)]
+    pub(crate) fn from_spirv_asm(text: &str) -> error::Result<Vec<u32>> {
+        use spirv_tools::assembler::{self, Assembler};
+
+        let binary = assembler::create(None)
+            .assemble(text, assembler::AssemblerOptions::default())
+            .map_err(|e| SpirvCrossError::InvalidSpirv(e.to_string()))?;
+
+        Ok(binary.as_words().to_vec())
+    }
+
+    /// Write a [naga](https://crates.io/crates/naga) module into SPIR-V words,
+    /// to be passed to [`Module::from_words`].
+    ///
+    /// The module is written with the default options of the naga SPIR-V backend.
+    /// If the naga SPIR-V writer fails, returns [`SpirvCrossError::Interop`].
+    #[cfg(feature = "naga")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "naga")))]
+    pub fn from_naga(
+        module: &naga::Module,
+        info: &naga::valid::ModuleInfo,
+    ) -> error::Result<Vec<u32>> {
+        naga::back::spv::write_vec(module, info, &naga::back::spv::Options::default(), None)
+            .map_err(|e| SpirvCrossError::Interop(e.to_string()))
     }
 }
 
@@ -219,9 +370,22 @@ pub trait ToStatic: Sealed {
 /// Once compiled into a [`CompiledArtifact`](compile::CompiledArtifact),
 /// reflection methods will still remain available, but the instance will be frozen,
 /// and no more mutation will be available.
+///
+/// Every compiler instance owns its own reference-counted SPIRV-Cross context, which is
+/// kept alive by the compiler and any strings or artifacts that borrow from it. There is no
+/// compiler that borrows its context, so compiler instances are always safe to store for `'static`.
 pub struct Compiler<T> {
     pub(crate) ptr: NonNull<spvc_compiler_s>,
     ctx: CrossAllocationCell,
+    /// Extensions that must not be required by the compiled output.
+    pub(crate) forbidden_extensions: Vec<String>,
+    /// Variables that are always kept in the set of enabled interface variables.
+    pub(crate) pinned_variables: Vec<VariableId>,
+    /// Shader resources cached by [`Compiler::shader_resources`], reset by mutations that may
+    /// change the reflected resources.
+    pub(crate) shader_resources: Cell<Option<NonNull<spvc_resources_s>>>,
+    /// The source language declared by `OpSource` in the module this compiler was created from.
+    pub(crate) source_language: Option<(spirv::SourceLanguage, u32)>,
     _pd: PhantomData<T>,
 }
 
@@ -232,6 +396,64 @@ impl<T: Target> Compiler<T> {
         allocs.into_compiler(spirv)
     }
 
+    /// Create a compiler instance for every entry point of a SPIR-V module.
+    ///
+    /// The module is parsed only once, and each compiler instance is created from a copy
+    /// of the parsed module with [`Compiler::set_entry_point`] applied. The instances do not
+    /// share any state, so each of them can be sent to a different thread to be reflected
+    /// or compiled in parallel.
+    pub fn new_for_entry_points(spirv: Module) -> error::Result<Vec<Compiler<T>>> {
+        let module: Compiler<targets::None> = Compiler::new(spirv)?;
+
+        let mut compilers = Vec::new();
+        for entry_point in module.entry_points()? {
+            let mut compiler = CrossAllocationCell::new()?.copy_compiler(&module)?;
+            compiler.set_entry_point(entry_point.name, entry_point.execution_model)?;
+            compilers.push(compiler);
+        }
+
+        Ok(compilers)
+    }
+
+    /// Get the backend that this compiler instance targets.
+    ///
+    /// This is useful for dispatching on compilers stored behind
+    /// a generic or a trait object, where the target is not statically known.
+    pub fn backend(&self) -> targets::Backend {
+        T::BACKEND
+    }
+
+    /// Get the SPIR-V words of the module this compiler instance was parsed from.
+    ///
+    /// SPIRV-Cross can not serialize its parsed representation back to SPIR-V, so the
+    /// returned words are always those of the original module. Changes made through this
+    /// compiler instance, such as [`Compiler::set_decoration`] or flattening buffer blocks,
+    /// are **not** reflected in the returned words.
+    ///
+    /// The words are returned in native byte order, even if the module was
+    /// created from byte-swapped bytes.
+    pub fn spirv_words(&self) -> error::Result<Vec<u32>> {
+        unsafe {
+            let mut length = 0;
+            spirv_cross_sys::spvc_rs_compiler_get_spirv_words(
+                self.ptr.as_ptr(),
+                std::ptr::null_mut(),
+                &mut length,
+            )
+            .ok(self)?;
+
+            let mut words = vec![0u32; length];
+            spirv_cross_sys::spvc_rs_compiler_get_spirv_words(
+                self.ptr.as_ptr(),
+                words.as_mut_ptr(),
+                &mut length,
+            )
+            .ok(self)?;
+
+            Ok(words)
+        }
+    }
+
     /// Create a new compiler instance.
     ///
     /// The pointer to the `spvc_compiler_s` must have the same lifetime as the context root.
@@ -242,6 +464,10 @@ impl<T: Target> Compiler<T> {
         Compiler {
             ptr,
             ctx,
+            forbidden_extensions: Vec::new(),
+            pinned_variables: Vec::new(),
+            shader_resources: Cell::new(None),
+            source_language: None,
             _pd: PhantomData,
         }
     }
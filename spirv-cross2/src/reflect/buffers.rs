@@ -1,18 +1,78 @@
 use crate::error;
-use crate::error::ToContextError;
+use crate::error::{SpirvCrossError, ToContextError};
 use crate::handle::Handle;
+use crate::reflect::{ResourceType, StructType, TypeInner};
+use crate::string::CompilerStr;
 use crate::Compiler;
 use spirv_cross_sys as sys;
-use spirv_cross_sys::{SpvId, VariableId};
+use spirv_cross_sys::{SpvId, TypeId, VariableId};
 
 /// A range over a buffer.
 pub use spirv_cross_sys::BufferRange;
 
+/// The layout of a buffer block, returned by [`Compiler::buffer_block_layout`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BufferBlockLayout<'a> {
+    /// The struct type of the buffer block.
+    pub type_id: Handle<TypeId>,
+    /// The declared size of the buffer block in bytes.
+    ///
+    /// If the buffer block ends with a runtime array, the runtime array is
+    /// considered to have zero elements.
+    pub size: usize,
+    /// The layout of the members of the buffer block.
+    pub members: Vec<BufferMemberLayout<'a>>,
+}
+
+/// The layout of a member of a buffer block.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BufferMemberLayout<'a> {
+    /// The name of the member, if any.
+    pub name: Option<CompilerStr<'a>>,
+    /// The index of the member inside its parent struct.
+    pub index: usize,
+    /// The offset in bytes from the beginning of the buffer block.
+    pub offset: u32,
+    /// The declared size of the member in bytes.
+    ///
+    /// Runtime arrays have a declared size of 0.
+    pub size: usize,
+    /// The array stride of the member, if it is an array.
+    pub array_stride: Option<u32>,
+    /// The matrix stride of the member, if it is a matrix or an array of matrices.
+    pub matrix_stride: Option<u32>,
+    /// The type of the member.
+    pub type_id: Handle<TypeId>,
+    /// Whether the member is a trailing runtime array.
+    ///
+    /// The offset of element `i` of the runtime array is `offset + i * array_stride`.
+    pub runtime_array: bool,
+    /// The layout of the members of the member, if it is a struct or an array of structs.
+    ///
+    /// For arrays of structs, the offsets are of the members of the first element of the array.
+    pub members: Vec<BufferMemberLayout<'a>>,
+}
+
+/// The range of a push constant block that is used by a shader,
+/// returned by [`Compiler::push_constant_range`].
+///
+/// This corresponds to `VkPushConstantRange`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PushConstantRange {
+    /// The offset in bytes of the start of the range.
+    pub offset: u32,
+    /// The size in bytes of the range.
+    pub size: u32,
+}
+
 /// Reflection of buffers (UBO, SSBOs, and PushConstant blocks).
 impl<T> Compiler<T> {
     /// Returns a list of which members of a struct are potentially in use by a
     /// SPIR-V shader. The granularity of this analysis is per-member of a struct.
     /// This can be used for Buffer (UBO), BufferBlock/StorageBuffer (SSBO) and PushConstant blocks.
+    ///
+    /// The returned ranges are borrowed from the compiler instance, and can be copied
+    /// with [`slice::to_vec`] if they need to outlive it.
     pub fn active_buffer_ranges(
         &self,
         handle: impl Into<Handle<VariableId>>,
@@ -35,6 +95,103 @@ impl<T> Compiler<T> {
         }
     }
 
+    /// Get the range of the push constant block that is used by the current entry point.
+    ///
+    /// The range spans from the lowest offset to the highest end of the
+    /// [active buffer ranges](Compiler::active_buffer_ranges) of the push constant block.
+    ///
+    /// Returns `None` if the module has no push constant block, or if no member
+    /// of the push constant block is used.
+    pub fn push_constant_range(&self) -> error::Result<Option<PushConstantRange>> {
+        let resources = self.shader_resources()?;
+        let Some(block) = resources
+            .resources_for_type(ResourceType::PushConstant)?
+            .next()
+        else {
+            return Ok(None);
+        };
+
+        let ranges = self.active_buffer_ranges(block.id)?;
+        let Some(start) = ranges.iter().map(|range| range.offset).min() else {
+            return Ok(None);
+        };
+        let end = ranges
+            .iter()
+            .map(|range| range.offset + range.range)
+            .max()
+            .unwrap_or(start);
+
+        Ok(Some(PushConstantRange {
+            offset: start as u32,
+            size: (end - start) as u32,
+        }))
+    }
+
+    /// Get the layout of a buffer block (UBO, SSBO, or PushConstant block) as a tree,
+    /// with the offsets, sizes, and strides of all members, including members of nested structs.
+    ///
+    /// If the variable is an array of buffer blocks, the layout of a single block is returned.
+    /// If the variable is not a buffer block, returns [`SpirvCrossError::InvalidArgument`].
+    pub fn buffer_block_layout(
+        &self,
+        handle: impl Into<Handle<VariableId>>,
+    ) -> error::Result<BufferBlockLayout> {
+        let mut ty = self.type_description(self.variable_type(handle)?)?;
+        while let TypeInner::Pointer { base, .. } | TypeInner::Array { base, .. } = ty.inner {
+            ty = self.type_description(base)?;
+        }
+
+        let TypeInner::Struct(struct_type) = ty.inner else {
+            return Err(SpirvCrossError::InvalidArgument(String::from(
+                "The variable is not a buffer block",
+            )));
+        };
+
+        Ok(BufferBlockLayout {
+            type_id: struct_type.id,
+            size: struct_type.size,
+            members: self.buffer_member_layouts(struct_type, 0)?,
+        })
+    }
+
+    fn buffer_member_layouts<'a>(
+        &'a self,
+        struct_type: StructType<'a>,
+        base_offset: u32,
+    ) -> error::Result<Vec<BufferMemberLayout<'a>>> {
+        let runtime_array = self.struct_has_runtime_array(&struct_type)?.is_some();
+        let member_count = struct_type.members.len();
+
+        let mut layouts = Vec::with_capacity(member_count);
+        for member in struct_type.members {
+            let offset = base_offset + member.offset;
+
+            let mut ty = self.type_description(member.id)?;
+            while let TypeInner::Array { base, .. } = ty.inner {
+                ty = self.type_description(base)?;
+            }
+
+            let members = match ty.inner {
+                TypeInner::Struct(inner) => self.buffer_member_layouts(inner, offset)?,
+                _ => Vec::new(),
+            };
+
+            layouts.push(BufferMemberLayout {
+                runtime_array: runtime_array && member.index + 1 == member_count,
+                name: member.name,
+                index: member.index,
+                offset,
+                size: member.size,
+                array_stride: member.array_stride,
+                matrix_stride: member.matrix_stride,
+                type_id: member.id,
+                members,
+            });
+        }
+
+        Ok(layouts)
+    }
+
     /// Queries if a buffer object has a neighbor "counter" buffer.
     /// If so, the ID of that counter buffer will be returned.
     ///
@@ -87,7 +244,163 @@ mod test {
         let ubo = ubo[0].id;
         let ranges = compiler.active_buffer_ranges(ubo)?;
 
-        eprintln!("{:?}", ranges);
+        // The UBO in basic.spv is declared but never accessed.
+        assert!(ranges.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    pub fn push_constant_range() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+
+        // basic.spv has no push constant block.
+        assert_eq!(None, compiler.push_constant_range()?);
+        Ok(())
+    }
+
+    #[test]
+    pub fn push_constant_range_used_members() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Fragment %main "main" %color
+            OpExecutionMode %main OriginUpperLeft
+            OpDecorate %color Location 0
+            OpDecorate %Constants Block
+            OpMemberDecorate %Constants 0 Offset 0
+            OpMemberDecorate %Constants 1 Offset 16
+            OpMemberDecorate %Constants 2 Offset 32
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %int = OpTypeInt 32 1
+            %float = OpTypeFloat 32
+            %v4float = OpTypeVector %float 4
+            %Constants = OpTypeStruct %float %v4float %float
+            %ptr_constants = OpTypePointer PushConstant %Constants
+            %ptr_v4float = OpTypePointer PushConstant %v4float
+            %ptr_float = OpTypePointer PushConstant %float
+            %ptr_color = OpTypePointer Output %v4float
+            %int_1 = OpConstant %int 1
+            %int_2 = OpConstant %int 2
+            %constants = OpVariable %ptr_constants PushConstant
+            %color = OpVariable %ptr_color Output
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            %tint_ptr = OpAccessChain %ptr_v4float %constants %int_1
+            %tint = OpLoad %v4float %tint_ptr
+            %scale_ptr = OpAccessChain %ptr_float %constants %int_2
+            %scale = OpLoad %float %scale_ptr
+            %value = OpVectorTimesScalar %v4float %tint %scale
+            OpStore %color %value
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+
+        // The first member is never used, so the range spans the last two members.
+        let range = compiler
+            .push_constant_range()?
+            .expect("push constant block is used");
+        assert_eq!(16, range.offset);
+        assert_eq!(20, range.size);
+        Ok(())
+    }
+
+    #[test]
+    pub fn buffer_block_layout() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let ubo: Vec<_> = compiler
+            .shader_resources()?
+            .resources_for_type(ResourceType::UniformBuffer)?
+            .collect();
+
+        let layout = compiler.buffer_block_layout(ubo[0].id)?;
+        assert_eq!(ubo[0].base_type_id, layout.type_id);
+        assert_eq!(64, layout.size);
+        assert_eq!(1, layout.members.len());
+
+        let mvp = &layout.members[0];
+        assert_eq!(0, mvp.offset);
+        assert_eq!(64, mvp.size);
+        assert_eq!(Some(16), mvp.matrix_stride);
+        assert_eq!(None, mvp.array_stride);
+        assert!(!mvp.runtime_array);
+        assert!(mvp.members.is_empty());
+
+        // struct Light { vec3 position; float radius; };
+        // buffer SSBO { uint count; Light lights[2]; vec4 data[]; };
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint GLCompute %main "main" %ssbo
+            OpExecutionMode %main LocalSize 1 1 1
+            OpMemberName %block 2 "data"
+            OpMemberDecorate %light 0 Offset 0
+            OpMemberDecorate %light 1 Offset 12
+            OpDecorate %arr_light ArrayStride 16
+            OpDecorate %rt_v4float ArrayStride 16
+            OpDecorate %block Block
+            OpMemberDecorate %block 0 Offset 0
+            OpMemberDecorate %block 1 Offset 16
+            OpMemberDecorate %block 2 Offset 48
+            OpDecorate %ssbo DescriptorSet 0
+            OpDecorate %ssbo Binding 0
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %uint = OpTypeInt 32 0
+            %uint_2 = OpConstant %uint 2
+            %float = OpTypeFloat 32
+            %v3float = OpTypeVector %float 3
+            %v4float = OpTypeVector %float 4
+            %light = OpTypeStruct %v3float %float
+            %arr_light = OpTypeArray %light %uint_2
+            %rt_v4float = OpTypeRuntimeArray %v4float
+            %block = OpTypeStruct %uint %arr_light %rt_v4float
+            %_ptr_StorageBuffer_block = OpTypePointer StorageBuffer %block
+            %ssbo = OpVariable %_ptr_StorageBuffer_block StorageBuffer
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        let ssbo: Vec<_> = compiler
+            .shader_resources()?
+            .resources_for_type(ResourceType::StorageBuffer)?
+            .collect();
+
+        let layout = compiler.buffer_block_layout(ssbo[0].id)?;
+        assert_eq!(48, layout.size);
+        assert_eq!(3, layout.members.len());
+
+        let lights = &layout.members[1];
+        assert_eq!(16, lights.offset);
+        assert_eq!(Some(16), lights.array_stride);
+        assert!(!lights.runtime_array);
+        assert_eq!(2, lights.members.len());
+        assert_eq!(16, lights.members[0].offset);
+        assert_eq!(28, lights.members[1].offset);
+
+        let data = &layout.members[2];
+        assert_eq!(Some("data"), data.name.as_deref());
+        assert_eq!(48, data.offset);
+        assert_eq!(0, data.size);
+        assert_eq!(Some(16), data.array_stride);
+        assert!(data.runtime_array);
+        assert!(data.members.is_empty());
+
         Ok(())
     }
 }
@@ -1,7 +1,8 @@
 use crate::error::{SpirvCrossError, ToContextError};
 use crate::handle::{Handle, VariableId};
 use crate::iter::impl_iterator;
-use crate::{error, Compiler, PhantomCompiler};
+use crate::sealed::Sealed;
+use crate::{error, Compiler, PhantomCompiler, ToStatic};
 use spirv_cross_sys as sys;
 use std::slice;
 
@@ -33,6 +34,11 @@ impl_iterator!(CombinedImageSamplerIter<'_>: CombinedImageSampler
 } for [1]);
 
 /// A combined image sampler.
+///
+/// With the `serde` feature, handles are serialized as their `u32` ID part, and
+/// deserialized handles are detached from any compiler instance.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CombinedImageSampler {
     /// A handle to the created combined image sampler.
     pub combined_id: Handle<VariableId>,
@@ -42,7 +48,37 @@ pub struct CombinedImageSampler {
     pub sampler_id: Handle<VariableId>,
 }
 
+impl Sealed for CombinedImageSampler {}
+impl ToStatic for CombinedImageSampler {
+    type Static<'a>
+    = CombinedImageSampler
+    where
+        'a: 'static;
+
+    fn to_static(&self) -> Self::Static<'static> {
+        *self
+    }
+}
+
 impl<T> Compiler<T> {
+    /// Checks whether [`Compiler::create_dummy_sampler_for_combined_images`] would need to create
+    /// a dummy sampler, without modifying the compiler instance.
+    ///
+    /// This is the case when an OpImageFetch (texelFetch) opcode is used on a separate image
+    /// without a sampler in the current entry point.
+    pub fn needs_dummy_sampler(&self) -> error::Result<bool> {
+        unsafe {
+            let mut needs_sampler = false;
+            sys::spvc_rs_compiler_needs_dummy_sampler_for_combined_images(
+                self.ptr.as_ptr(),
+                &mut needs_sampler,
+            )
+            .ok(self)?;
+
+            Ok(needs_sampler)
+        }
+    }
+
     /// Analyzes all OpImageFetch (texelFetch) opcodes and checks if there are instances where
     /// said instruction is used without a combined image sampler.
     /// GLSL targets do not support the use of texelFetch without a sampler.
@@ -56,6 +92,7 @@ impl<T> Compiler<T> {
     pub fn create_dummy_sampler_for_combined_images(
         &mut self,
     ) -> error::Result<BuiltDummySamplerProof> {
+        self.invalidate_shader_resources();
         unsafe {
             let mut var_id = VariableId::from(0);
             sys::spvc_compiler_build_dummy_sampler_for_combined_images(
@@ -105,6 +142,7 @@ impl<T> Compiler<T> {
             )));
         }
 
+        self.invalidate_shader_resources();
         unsafe {
             sys::spvc_compiler_build_combined_image_samplers(self.ptr.as_ptr()).ok(&*self)?;
 
@@ -113,6 +151,13 @@ impl<T> Compiler<T> {
     }
 
     /// Gets a remapping for the combined image samplers.
+    ///
+    /// The `combined_id` of each remapping can be named with [`Compiler::set_name`],
+    /// and decorated with [`Compiler::set_decoration`] before compiling, to control how the
+    /// generated combined image samplers are declared.
+    ///
+    /// For MSL, the suffix appended to the generated sampler half of a combined image sampler
+    /// can be set with `Compiler<Msl>::set_combined_sampler_suffix`.
     pub fn combined_image_samplers(&self) -> error::Result<CombinedImageSamplerIter<'static>> {
         unsafe {
             let mut samplers = std::ptr::null();
@@ -130,16 +175,116 @@ impl<T> Compiler<T> {
             Ok(CombinedImageSamplerIter(self.phantom(), slice.iter()))
         }
     }
+
+    /// Assign descriptor set and binding decorations to every combined image sampler
+    /// created by [`Compiler::build_combined_image_samplers`].
+    ///
+    /// Every combined image sampler is decorated with the descriptor set `set`, and
+    /// bindings are assigned consecutively starting from `binding`, in the order returned by
+    /// [`Compiler::combined_image_samplers`]. The order is deterministic for a given module and
+    /// entry point.
+    ///
+    /// This must be called after [`Compiler::build_combined_image_samplers`], otherwise
+    /// there are no combined image samplers to decorate and this does nothing.
+    ///
+    /// If the assigned bindings would overflow a `u32`, returns
+    /// [`SpirvCrossError::InvalidArgument`] without decorating any combined image sampler.
+    pub fn set_combined_image_sampler_base_binding(
+        &mut self,
+        set: u32,
+        binding: u32,
+    ) -> error::Result<()> {
+        let samplers: Vec<CombinedImageSampler> = self.combined_image_samplers()?.collect();
+
+        // The last assigned binding is binding + count - 1.
+        let fits = match u32::try_from(samplers.len()) {
+            Ok(0) => true,
+            Ok(count) => binding.checked_add(count - 1).is_some(),
+            Err(_) => false,
+        };
+
+        if !fits {
+            return Err(SpirvCrossError::InvalidArgument(format!(
+                "{} combined image samplers can not be bound starting from binding {binding}",
+                samplers.len()
+            )));
+        }
+
+        for (offset, sampler) in samplers.into_iter().enumerate() {
+            // Can not overflow, the number of samplers was checked above.
+            let binding = binding + offset as u32;
+            self.set_decoration(
+                sampler.combined_id,
+                spirv::Decoration::DescriptorSet,
+                Some(set),
+            )?;
+            self.set_decoration(
+                sampler.combined_id,
+                spirv::Decoration::Binding,
+                Some(binding),
+            )?;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::error::SpirvCrossError;
+    use crate::reflect::DecorationValue;
     use crate::Compiler;
     use crate::{targets, Module};
 
     static BASIC_SPV: &[u8] = include_bytes!("../../basic.spv");
 
+    // Two separate images sampled with the same separate sampler.
+    static SEPARATE_SAMPLERS_ASM: &str = r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main" %color %uv
+        OpExecutionMode %main OriginUpperLeft
+        OpDecorate %color Location 0
+        OpDecorate %uv Location 0
+        OpDecorate %albedo DescriptorSet 0
+        OpDecorate %albedo Binding 0
+        OpDecorate %normal DescriptorSet 0
+        OpDecorate %normal Binding 1
+        OpDecorate %smp DescriptorSet 0
+        OpDecorate %smp Binding 2
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %float = OpTypeFloat 32
+        %v2float = OpTypeVector %float 2
+        %v4float = OpTypeVector %float 4
+        %image = OpTypeImage %float 2D 0 0 0 1 Unknown
+        %sampler = OpTypeSampler
+        %sampled_image = OpTypeSampledImage %image
+        %ptr_image = OpTypePointer UniformConstant %image
+        %ptr_sampler = OpTypePointer UniformConstant %sampler
+        %ptr_color = OpTypePointer Output %v4float
+        %ptr_uv = OpTypePointer Input %v2float
+        %albedo = OpVariable %ptr_image UniformConstant
+        %normal = OpVariable %ptr_image UniformConstant
+        %smp = OpVariable %ptr_sampler UniformConstant
+        %color = OpVariable %ptr_color Output
+        %uv = OpVariable %ptr_uv Input
+        %main = OpFunction %void None %fn
+        %entry = OpLabel
+        %coord = OpLoad %v2float %uv
+        %s = OpLoad %sampler %smp
+        %a = OpLoad %image %albedo
+        %sa = OpSampledImage %sampled_image %a %s
+        %ca = OpImageSampleImplicitLod %v4float %sa %coord
+        %n = OpLoad %image %normal
+        %sn = OpSampledImage %sampled_image %n %s
+        %cn = OpImageSampleImplicitLod %v4float %sn %coord
+        %sum = OpFAdd %v4float %ca %cn
+        OpStore %color %sum
+        OpReturn
+        OpFunctionEnd
+        "#;
+
     #[test]
     pub fn test_combined_image_sampler_build() -> Result<(), SpirvCrossError> {
         let vec = Vec::from(BASIC_SPV);
@@ -147,9 +292,14 @@ mod test {
 
         let mut compiler: Compiler<targets::None> = Compiler::new(words)?;
 
+        let needs_sampler = compiler.needs_dummy_sampler()?;
         let proof = compiler.create_dummy_sampler_for_combined_images()?;
+        assert_eq!(needs_sampler, proof.sampler_id.is_some());
         compiler.build_combined_image_samplers(proof)?;
 
+        // basic.spv only uses an already combined image sampler, so nothing is remapped.
+        assert_eq!(0, compiler.combined_image_samplers()?.count());
+
         // match ty.inner {
         //     TypeInner::Struct(ty) => {
         //         compiler.get_type(ty.members[0].id)?;
@@ -159,4 +309,64 @@ mod test {
         // }
         Ok(())
     }
+
+    #[test]
+    pub fn combined_image_sampler_base_binding() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(SEPARATE_SAMPLERS_ASM)?;
+
+        let mut compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        let proof = compiler.create_dummy_sampler_for_combined_images()?;
+        compiler.build_combined_image_samplers(proof)?;
+
+        let samplers: Vec<_> = compiler.combined_image_samplers()?.collect();
+        assert_eq!(2, samplers.len());
+
+        compiler.set_combined_image_sampler_base_binding(2, 5)?;
+        for (sampler, binding) in samplers.iter().zip([5, 6]) {
+            assert_eq!(
+                Some(DecorationValue::Literal(2)),
+                compiler.decoration(sampler.combined_id, spirv::Decoration::DescriptorSet)?
+            );
+            assert_eq!(
+                Some(DecorationValue::Literal(binding)),
+                compiler.decoration(sampler.combined_id, spirv::Decoration::Binding)?
+            );
+        }
+
+        // The last binding would be u32::MAX + 1.
+        assert!(matches!(
+            compiler.set_combined_image_sampler_base_binding(0, u32::MAX),
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+
+        compiler.set_combined_image_sampler_base_binding(0, u32::MAX - 1)?;
+        assert_eq!(
+            Some(DecorationValue::Literal(u32::MAX)),
+            compiler.decoration(samplers[1].combined_id, spirv::Decoration::Binding)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "glsl")]
+    pub fn combined_image_sampler_set_name() -> Result<(), SpirvCrossError> {
+        use crate::compile::CompilableTarget;
+
+        let words = Module::from_spirv_asm(SEPARATE_SAMPLERS_ASM)?;
+
+        let mut compiler: Compiler<targets::Glsl> = Compiler::new(Module::from_words(&words))?;
+        let proof = compiler.create_dummy_sampler_for_combined_images()?;
+        compiler.build_combined_image_samplers(proof)?;
+
+        let samplers: Vec<_> = compiler.combined_image_samplers()?.collect();
+        compiler.set_name(samplers[0].combined_id, "albedo_smp")?;
+        compiler.set_name(samplers[1].combined_id, "normal_smp")?;
+
+        let artifact = compiler.compile(&targets::Glsl::options())?;
+        assert!(artifact.as_ref().contains("uniform sampler2D albedo_smp;"));
+        assert!(artifact.as_ref().contains("uniform sampler2D normal_smp;"));
+
+        Ok(())
+    }
 }
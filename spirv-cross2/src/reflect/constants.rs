@@ -1,12 +1,15 @@
 use crate::sealed::Sealed;
 use spirv_cross_sys::{spvc_constant, spvc_specialization_constant, TypeId};
 use std::mem::MaybeUninit;
+use std::num::TryFromIntError;
 use std::ops::{Index, IndexMut};
 use std::slice;
 
 use crate::error::{SpirvCrossError, ToContextError};
 use crate::handle::{ConstantId, Handle};
 use crate::iter::impl_iterator;
+use crate::reflect::{BitWidth, Scalar, ScalarKind, TypeInner, TypeKind};
+use crate::string::CompilerStr;
 use crate::{error, Compiler, PhantomCompiler};
 use spirv_cross_sys as sys;
 
@@ -95,6 +98,42 @@ pub struct SpecializationConstant {
     pub constant_id: u32,
 }
 
+/// The default value of a scalar specialization constant.
+///
+/// Integers and floating point numbers are widened to 64 bits.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SpecializationConstantValue {
+    /// A boolean value.
+    Bool(bool),
+    /// A signed integer value.
+    Int(i64),
+    /// An unsigned integer value.
+    Uint(u64),
+    /// A floating point value.
+    Float(f64),
+}
+
+/// A SPIR-V specialization constant with its name, type and default value resolved,
+/// created by [`Compiler::specialization_constants_detailed`].
+#[derive(Debug, Clone)]
+pub struct SpecializationConstantInfo<'a> {
+    /// The handle to the constant.
+    pub id: Handle<ConstantId>,
+    /// The declared `constant_id` of the constant.
+    pub constant_id: u32,
+    /// The name of the constant, if any.
+    pub name: Option<CompilerStr<'a>>,
+    /// The type of the constant.
+    pub type_id: Handle<TypeId>,
+    /// The top-level kind of the type of the constant.
+    pub kind: TypeKind,
+    /// The default value of the constant, if it is a scalar.
+    ///
+    /// Composite specialization constants are made up of
+    /// [subconstants](Compiler::specialization_sub_constants), which can be queried separately.
+    pub value: Option<SpecializationConstantValue>,
+}
+
 /// Specialization constants for a workgroup size.
 #[derive(Debug, Clone)]
 pub struct WorkgroupSizeSpecializationConstants {
@@ -329,6 +368,195 @@ impl<T> Compiler<T> {
 
         Ok(type_id)
     }
+
+    /// Query declared specialization constants, along with their names, types
+    /// and default values.
+    pub fn specialization_constants_detailed(
+        &self,
+    ) -> error::Result<Vec<SpecializationConstantInfo>> {
+        let mut constants = Vec::new();
+        for constant in self.specialization_constants()? {
+            let type_id = self.specialization_constant_type(constant.id)?;
+            constants.push(SpecializationConstantInfo {
+                id: constant.id,
+                constant_id: constant.constant_id,
+                name: self.name(constant.id)?,
+                type_id,
+                kind: self.type_kind(type_id)?,
+                value: self.specialization_constant_scalar_value(constant.id)?,
+            });
+        }
+
+        Ok(constants)
+    }
+
+    /// Get the value of a scalar specialization constant, typed according to the
+    /// declared type of the constant.
+    ///
+    /// Unless it has been changed with [`Compiler::set_specialization_constant_value`],
+    /// this is the default value of the constant declared in the SPIR-V module.
+    ///
+    /// Returns `None` if the constant is not a scalar.
+    pub fn specialization_constant_scalar_value(
+        &self,
+        handle: Handle<ConstantId>,
+    ) -> error::Result<Option<SpecializationConstantValue>> {
+        let type_id = self.specialization_constant_type(handle)?;
+        let TypeInner::Scalar(scalar) = self.type_description(type_id)?.inner else {
+            return Ok(None);
+        };
+
+        self.specialization_constant_typed_scalar(handle, &scalar)
+            .map(Some)
+    }
+
+    /// Set the value of a scalar specialization constant, converting the value to the
+    /// declared type of the constant.
+    ///
+    /// If the constant is not a scalar, the kind of the value does not match the declared type,
+    /// or an integer value is out of range for the declared bit width, returns
+    /// [`SpirvCrossError::InvalidArgument`]. Setting a 16-bit floating point constant requires
+    /// the `f16` feature.
+    pub fn set_specialization_constant_scalar_value(
+        &mut self,
+        handle: Handle<ConstantId>,
+        value: SpecializationConstantValue,
+    ) -> error::Result<()> {
+        use SpecializationConstantValue as Value;
+
+        let type_id = self.specialization_constant_type(handle)?;
+        let TypeInner::Scalar(scalar) = self.type_description(type_id)?.inner else {
+            return Err(SpirvCrossError::InvalidArgument(String::from(
+                "The specialization constant is not a scalar",
+            )));
+        };
+
+        let out_of_range = |_: TryFromIntError| {
+            SpirvCrossError::InvalidArgument(format!(
+                "The value {value:?} is out of range for the specialization constant"
+            ))
+        };
+
+        match (scalar.kind, scalar.size, value) {
+            (ScalarKind::Bool, _, Value::Bool(value)) => {
+                self.set_specialization_constant_scalar(handle, 0, 0, value)
+            }
+            (ScalarKind::Int, BitWidth::Byte, Value::Int(value)) => {
+                let value = i8::try_from(value).map_err(out_of_range)?;
+                self.set_specialization_constant_scalar(handle, 0, 0, value)
+            }
+            (ScalarKind::Int, BitWidth::HalfWord, Value::Int(value)) => {
+                let value = i16::try_from(value).map_err(out_of_range)?;
+                self.set_specialization_constant_scalar(handle, 0, 0, value)
+            }
+            (ScalarKind::Int, BitWidth::DoubleWord, Value::Int(value)) => {
+                self.set_specialization_constant_scalar(handle, 0, 0, value)
+            }
+            (ScalarKind::Int, _, Value::Int(value)) => {
+                let value = i32::try_from(value).map_err(out_of_range)?;
+                self.set_specialization_constant_scalar(handle, 0, 0, value)
+            }
+            (ScalarKind::Uint, BitWidth::Byte, Value::Uint(value)) => {
+                let value = u8::try_from(value).map_err(out_of_range)?;
+                self.set_specialization_constant_scalar(handle, 0, 0, value)
+            }
+            (ScalarKind::Uint, BitWidth::HalfWord, Value::Uint(value)) => {
+                let value = u16::try_from(value).map_err(out_of_range)?;
+                self.set_specialization_constant_scalar(handle, 0, 0, value)
+            }
+            (ScalarKind::Uint, BitWidth::DoubleWord, Value::Uint(value)) => {
+                self.set_specialization_constant_scalar(handle, 0, 0, value)
+            }
+            (ScalarKind::Uint, _, Value::Uint(value)) => {
+                let value = u32::try_from(value).map_err(out_of_range)?;
+                self.set_specialization_constant_scalar(handle, 0, 0, value)
+            }
+            #[cfg(feature = "f16")]
+            (ScalarKind::Float, BitWidth::HalfWord, Value::Float(value)) => {
+                self.set_specialization_constant_scalar(handle, 0, 0, ::half::f16::from_f64(value))
+            }
+            #[cfg(not(feature = "f16"))]
+            (ScalarKind::Float, BitWidth::HalfWord, Value::Float(_)) => {
+                Err(SpirvCrossError::InvalidArgument(String::from(
+                    "Setting a 16-bit floating point constant requires the `f16` feature",
+                )))
+            }
+            (ScalarKind::Float, BitWidth::DoubleWord, Value::Float(value)) => {
+                self.set_specialization_constant_scalar(handle, 0, 0, value)
+            }
+            (ScalarKind::Float, _, Value::Float(value)) => {
+                self.set_specialization_constant_scalar(handle, 0, 0, value as f32)
+            }
+            _ => Err(SpirvCrossError::InvalidArgument(format!(
+                "The value {value:?} does not match the type of the specialization constant"
+            ))),
+        }
+    }
+
+    fn specialization_constant_typed_scalar(
+        &self,
+        handle: Handle<ConstantId>,
+        scalar: &Scalar,
+    ) -> error::Result<SpecializationConstantValue> {
+        use SpecializationConstantValue as Value;
+
+        Ok(match (scalar.kind, scalar.size) {
+            (ScalarKind::Bool, _) => {
+                let value: bool = self.specialization_constant_scalar(handle, 0, 0)?;
+                Value::Bool(value)
+            }
+            (ScalarKind::Int, BitWidth::Byte) => {
+                let value: i8 = self.specialization_constant_scalar(handle, 0, 0)?;
+                Value::Int(value.into())
+            }
+            (ScalarKind::Int, BitWidth::HalfWord) => {
+                let value: i16 = self.specialization_constant_scalar(handle, 0, 0)?;
+                Value::Int(value.into())
+            }
+            (ScalarKind::Int, BitWidth::DoubleWord) => {
+                let value: i64 = self.specialization_constant_scalar(handle, 0, 0)?;
+                Value::Int(value)
+            }
+            (ScalarKind::Int, _) => {
+                let value: i32 = self.specialization_constant_scalar(handle, 0, 0)?;
+                Value::Int(value.into())
+            }
+            (ScalarKind::Uint, BitWidth::Byte) => {
+                let value: u8 = self.specialization_constant_scalar(handle, 0, 0)?;
+                Value::Uint(value.into())
+            }
+            (ScalarKind::Uint, BitWidth::HalfWord) => {
+                let value: u16 = self.specialization_constant_scalar(handle, 0, 0)?;
+                Value::Uint(value.into())
+            }
+            (ScalarKind::Uint, BitWidth::DoubleWord) => {
+                let value: u64 = self.specialization_constant_scalar(handle, 0, 0)?;
+                Value::Uint(value)
+            }
+            (ScalarKind::Uint, _) => {
+                let value: u32 = self.specialization_constant_scalar(handle, 0, 0)?;
+                Value::Uint(value.into())
+            }
+            (ScalarKind::Float, BitWidth::HalfWord) => {
+                let constant = self.yield_id(handle)?;
+                let value = unsafe {
+                    // SAFETY: yield_id ensures safety.
+                    let handle =
+                        sys::spvc_compiler_get_constant_handle(self.ptr.as_ptr(), constant);
+                    sys::spvc_constant_get_scalar_fp16(handle, 0, 0)
+                };
+                Value::Float(value.into())
+            }
+            (ScalarKind::Float, BitWidth::DoubleWord) => {
+                let value: f64 = self.specialization_constant_scalar(handle, 0, 0)?;
+                Value::Float(value)
+            }
+            (ScalarKind::Float, _) => {
+                let value: f32 = self.specialization_constant_scalar(handle, 0, 0)?;
+                Value::Float(value.into())
+            }
+        })
+    }
 }
 
 /// A marker trait for types that can be represented as a SPIR-V constant.
@@ -438,4 +666,175 @@ impl<T> Compiler<T> {
     }
 }
 
-pub(self) use impl_vec_constant;
\ No newline at end of file
+pub(self) use impl_vec_constant;
+#[cfg(test)]
+mod test {
+    use crate::error::SpirvCrossError;
+    use crate::handle::{ConstantId, Handle};
+    use crate::reflect::SpecializationConstantValue as Value;
+    use crate::reflect::TypeKind;
+    use crate::Compiler;
+    use crate::{targets, Module};
+
+    static SPEC_CONSTANTS_ASM: &str = r#"
+        OpCapability Shader
+        OpCapability Int16
+        OpCapability Float16
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint GLCompute %main "main"
+        OpExecutionMode %main LocalSize 1 1 1
+        OpName %count "count"
+        OpDecorate %count SpecId 0
+        OpDecorate %small SpecId 1
+        OpDecorate %scale SpecId 2
+        OpDecorate %half_scale SpecId 3
+        OpDecorate %enabled SpecId 4
+        OpDecorate %offset SpecId 5
+        OpDecorate %size BuiltIn WorkgroupSize
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %bool = OpTypeBool
+        %int = OpTypeInt 32 1
+        %uint = OpTypeInt 32 0
+        %ushort = OpTypeInt 16 0
+        %float = OpTypeFloat 32
+        %half = OpTypeFloat 16
+        %v3uint = OpTypeVector %uint 3
+        %count = OpSpecConstant %uint 8
+        %small = OpSpecConstant %ushort 4
+        %scale = OpSpecConstant %float 1.5
+        %half_scale = OpSpecConstant %half 0.5
+        %enabled = OpSpecConstantTrue %bool
+        %offset = OpSpecConstant %int -3
+        %uint_1 = OpConstant %uint 1
+        %size = OpSpecConstantComposite %v3uint %count %uint_1 %uint_1
+        %main = OpFunction %void None %fn
+        %entry = OpLabel
+        OpReturn
+        OpFunctionEnd
+        "#;
+
+    fn spec_constant<T>(
+        compiler: &Compiler<T>,
+        constant_id: u32,
+    ) -> Result<Handle<ConstantId>, SpirvCrossError> {
+        Ok(compiler
+            .specialization_constants()?
+            .find(|constant| constant.constant_id == constant_id)
+            .expect("the specialization constant is declared")
+            .id)
+    }
+
+    #[test]
+    pub fn specialization_constants_detailed() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(SPEC_CONSTANTS_ASM)?;
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+
+        let mut constants = compiler.specialization_constants_detailed()?;
+        constants.sort_by_key(|constant| constant.constant_id);
+        assert_eq!(6, constants.len());
+
+        let count = &constants[0];
+        assert_eq!(spec_constant(&compiler, 0)?, count.id);
+        assert_eq!(Some("count"), count.name.as_deref());
+        assert_eq!(TypeKind::Int, count.kind);
+        assert_eq!(
+            compiler.specialization_constant_type(count.id)?,
+            count.type_id
+        );
+        assert_eq!(Some(Value::Uint(8)), count.value);
+
+        let scale = &constants[2];
+        assert_eq!(None, scale.name);
+        assert_eq!(TypeKind::Float, scale.kind);
+        assert_eq!(Some(Value::Float(1.5)), scale.value);
+
+        let enabled = &constants[4];
+        assert_eq!(TypeKind::Bool, enabled.kind);
+        assert_eq!(Some(Value::Bool(true)), enabled.value);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn specialization_constant_scalar_value() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(SPEC_CONSTANTS_ASM)?;
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+
+        let expected = [
+            (0, Value::Uint(8)),
+            (1, Value::Uint(4)),
+            (2, Value::Float(1.5)),
+            (3, Value::Float(0.5)),
+            (4, Value::Bool(true)),
+            (5, Value::Int(-3)),
+        ];
+
+        for (constant_id, value) in expected {
+            let constant = spec_constant(&compiler, constant_id)?;
+            assert_eq!(
+                Some(value),
+                compiler.specialization_constant_scalar_value(constant)?
+            );
+        }
+
+        // The workgroup size is a composite, not a scalar.
+        let size = compiler
+            .work_group_size_specialization_constants()
+            .builtin_workgroup_size_handle
+            .expect("the workgroup size is a specialization constant");
+        assert_eq!(None, compiler.specialization_constant_scalar_value(size)?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn set_specialization_constant_scalar_value() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(SPEC_CONSTANTS_ASM)?;
+        let mut compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+
+        let count = spec_constant(&compiler, 0)?;
+        compiler.set_specialization_constant_scalar_value(count, Value::Uint(16))?;
+        assert_eq!(
+            Some(Value::Uint(16)),
+            compiler.specialization_constant_scalar_value(count)?
+        );
+
+        // 70000 does not fit in a 16-bit unsigned integer.
+        let small = spec_constant(&compiler, 1)?;
+        assert!(matches!(
+            compiler.set_specialization_constant_scalar_value(small, Value::Uint(70000)),
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+        assert_eq!(
+            Some(Value::Uint(4)),
+            compiler.specialization_constant_scalar_value(small)?
+        );
+
+        assert!(matches!(
+            compiler.set_specialization_constant_scalar_value(count, Value::Float(1.0)),
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            compiler.set_specialization_constant_scalar_value(count, Value::Int(1)),
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+
+        let half_scale = spec_constant(&compiler, 3)?;
+        #[cfg(feature = "f16")]
+        {
+            compiler.set_specialization_constant_scalar_value(half_scale, Value::Float(0.25))?;
+            assert_eq!(
+                Some(Value::Float(0.25)),
+                compiler.specialization_constant_scalar_value(half_scale)?
+            );
+        }
+        #[cfg(not(feature = "f16"))]
+        assert!(matches!(
+            compiler.set_specialization_constant_scalar_value(half_scale, Value::Float(0.25)),
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -33,7 +33,23 @@ pub enum DecorationValue<'a> {
     RoundingMode(spirv::FPRoundingMode),
     /// Only for decoration [`SpecId`](Decoration::SpecId).
     Constant(Handle<ConstantId>),
-    /// Only for decoration [`HlslSemanticGOOGLE`](Decoration::HlslSemanticGOOGLE) and [`UserTypeGOOGLE`](Decoration::HlslSemanticGOOGLE).
+    /// An ID operand, declared with `OpDecorateId`.
+    ///
+    /// Returned by the following decorations.
+    ///
+    /// - [`AlignmentId`](Decoration::AlignmentId).
+    /// - [`MaxByteOffsetId`](Decoration::MaxByteOffsetId).
+    /// - [`UniformId`](Decoration::UniformId).
+    ///
+    /// SPIRV-Cross does not keep the ID operand of an `OpDecorateId` in its parsed representation,
+    /// so the operand is read from the original SPIR-V binary. An ID decoration can only be read
+    /// if it was declared in the module, and can not be set with [`Compiler::set_decoration`].
+    ///
+    /// Layout decorations such as [`ArrayStride`](Decoration::ArrayStride) and
+    /// [`Offset`](Decoration::Offset) only take literal operands in SPIR-V, and can not be
+    /// declared with `OpDecorateId` or be driven by a specialization constant.
+    Id(Handle<ConstantId>),
+    /// Only for decoration [`HlslSemanticGOOGLE`](Decoration::HlslSemanticGOOGLE) and [`UserTypeGOOGLE`](Decoration::UserTypeGOOGLE).
     String(CompilerStr<'a>),
     /// All other decorations to indicate the presence of a decoration.
     Present,
@@ -104,10 +120,8 @@ impl ToStatic for DecorationValue<'_> {
             DecorationValue::BuiltIn(a) => DecorationValue::BuiltIn(*a),
             DecorationValue::RoundingMode(a) => DecorationValue::RoundingMode(*a),
             DecorationValue::Constant(a) => DecorationValue::Constant(*a),
-            DecorationValue::String(c) => {
-                let owned = c.to_string();
-                DecorationValue::String(CompilerStr::from_string(owned))
-            }
+            DecorationValue::Id(a) => DecorationValue::Id(*a),
+            DecorationValue::String(c) => DecorationValue::String(c.to_static()),
             DecorationValue::Present => DecorationValue::Present,
         }
     }
@@ -127,10 +141,12 @@ impl DecorationValue<'_> {
             DecorationValue::BuiltIn(_) => decoration == Decoration::BuiltIn,
             DecorationValue::RoundingMode(_) => decoration == Decoration::FPRoundingMode,
             DecorationValue::Constant(_) => decoration == Decoration::SpecId,
+            DecorationValue::Id(_) => decoration_is_id(decoration),
             DecorationValue::String(_) => decoration_is_string(decoration),
             DecorationValue::Present => {
                 !decoration_is_literal(decoration)
                     && !decoration_is_string(decoration)
+                    && !decoration_is_id(decoration)
                     && decoration != Decoration::BuiltIn
                     && decoration != Decoration::FPRoundingMode
                     && decoration != Decoration::SpecId
@@ -156,6 +172,150 @@ fn decoration_is_literal(decoration: spirv::Decoration) -> bool {
     }
 }
 
+/// Every decoration known to the `spirv` crate, checked in order by [`Compiler::decorations`].
+const KNOWN_DECORATIONS: &[Decoration] = &[
+    Decoration::RelaxedPrecision,
+    Decoration::SpecId,
+    Decoration::Block,
+    Decoration::BufferBlock,
+    Decoration::RowMajor,
+    Decoration::ColMajor,
+    Decoration::ArrayStride,
+    Decoration::MatrixStride,
+    Decoration::GLSLShared,
+    Decoration::GLSLPacked,
+    Decoration::CPacked,
+    Decoration::BuiltIn,
+    Decoration::NoPerspective,
+    Decoration::Flat,
+    Decoration::Patch,
+    Decoration::Centroid,
+    Decoration::Sample,
+    Decoration::Invariant,
+    Decoration::Restrict,
+    Decoration::Aliased,
+    Decoration::Volatile,
+    Decoration::Constant,
+    Decoration::Coherent,
+    Decoration::NonWritable,
+    Decoration::NonReadable,
+    Decoration::Uniform,
+    Decoration::UniformId,
+    Decoration::SaturatedConversion,
+    Decoration::Stream,
+    Decoration::Location,
+    Decoration::Component,
+    Decoration::Index,
+    Decoration::Binding,
+    Decoration::DescriptorSet,
+    Decoration::Offset,
+    Decoration::XfbBuffer,
+    Decoration::XfbStride,
+    Decoration::FuncParamAttr,
+    Decoration::FPRoundingMode,
+    Decoration::FPFastMathMode,
+    Decoration::LinkageAttributes,
+    Decoration::NoContraction,
+    Decoration::InputAttachmentIndex,
+    Decoration::Alignment,
+    Decoration::MaxByteOffset,
+    Decoration::AlignmentId,
+    Decoration::MaxByteOffsetId,
+    Decoration::NoSignedWrap,
+    Decoration::NoUnsignedWrap,
+    Decoration::WeightTextureQCOM,
+    Decoration::BlockMatchTextureQCOM,
+    Decoration::ExplicitInterpAMD,
+    Decoration::NodeSharesPayloadLimitsWithAMDX,
+    Decoration::NodeMaxPayloadsAMDX,
+    Decoration::TrackFinishWritingAMDX,
+    Decoration::PayloadNodeNameAMDX,
+    Decoration::OverrideCoverageNV,
+    Decoration::PassthroughNV,
+    Decoration::ViewportRelativeNV,
+    Decoration::SecondaryViewportRelativeNV,
+    Decoration::PerPrimitiveNV,
+    Decoration::PerViewNV,
+    Decoration::PerTaskNV,
+    Decoration::PerVertexKHR,
+    Decoration::NonUniform,
+    Decoration::RestrictPointer,
+    Decoration::AliasedPointer,
+    Decoration::HitObjectShaderRecordBufferNV,
+    Decoration::BindlessSamplerNV,
+    Decoration::BindlessImageNV,
+    Decoration::BoundSamplerNV,
+    Decoration::BoundImageNV,
+    Decoration::SIMTCallINTEL,
+    Decoration::ReferencedIndirectlyINTEL,
+    Decoration::ClobberINTEL,
+    Decoration::SideEffectsINTEL,
+    Decoration::VectorComputeVariableINTEL,
+    Decoration::FuncParamIOKindINTEL,
+    Decoration::VectorComputeFunctionINTEL,
+    Decoration::StackCallINTEL,
+    Decoration::GlobalVariableOffsetINTEL,
+    Decoration::CounterBuffer,
+    Decoration::UserSemantic,
+    Decoration::UserTypeGOOGLE,
+    Decoration::FunctionRoundingModeINTEL,
+    Decoration::FunctionDenormModeINTEL,
+    Decoration::RegisterINTEL,
+    Decoration::MemoryINTEL,
+    Decoration::NumbanksINTEL,
+    Decoration::BankwidthINTEL,
+    Decoration::MaxPrivateCopiesINTEL,
+    Decoration::SinglepumpINTEL,
+    Decoration::DoublepumpINTEL,
+    Decoration::MaxReplicatesINTEL,
+    Decoration::SimpleDualPortINTEL,
+    Decoration::MergeINTEL,
+    Decoration::BankBitsINTEL,
+    Decoration::ForcePow2DepthINTEL,
+    Decoration::BurstCoalesceINTEL,
+    Decoration::CacheSizeINTEL,
+    Decoration::DontStaticallyCoalesceINTEL,
+    Decoration::PrefetchINTEL,
+    Decoration::StallEnableINTEL,
+    Decoration::FuseLoopsInFunctionINTEL,
+    Decoration::MathOpDSPModeINTEL,
+    Decoration::AliasScopeINTEL,
+    Decoration::NoAliasINTEL,
+    Decoration::InitiationIntervalINTEL,
+    Decoration::MaxConcurrencyINTEL,
+    Decoration::PipelineEnableINTEL,
+    Decoration::BufferLocationINTEL,
+    Decoration::IOPipeStorageINTEL,
+    Decoration::FunctionFloatingPointModeINTEL,
+    Decoration::SingleElementVectorINTEL,
+    Decoration::VectorComputeCallableFunctionINTEL,
+    Decoration::MediaBlockIOINTEL,
+    Decoration::InitModeINTEL,
+    Decoration::ImplementInRegisterMapINTEL,
+    Decoration::HostAccessINTEL,
+    Decoration::FPMaxErrorDecorationINTEL,
+    Decoration::LatencyControlLabelINTEL,
+    Decoration::LatencyControlConstraintINTEL,
+    Decoration::ConduitKernelArgumentINTEL,
+    Decoration::RegisterMapKernelArgumentINTEL,
+    Decoration::MMHostInterfaceAddressWidthINTEL,
+    Decoration::MMHostInterfaceDataWidthINTEL,
+    Decoration::MMHostInterfaceLatencyINTEL,
+    Decoration::MMHostInterfaceReadWriteModeINTEL,
+    Decoration::MMHostInterfaceMaxBurstINTEL,
+    Decoration::MMHostInterfaceWaitRequestINTEL,
+    Decoration::StableKernelArgumentINTEL,
+    Decoration::CacheControlLoadINTEL,
+    Decoration::CacheControlStoreINTEL,
+];
+
+fn decoration_is_id(decoration: Decoration) -> bool {
+    match decoration {
+        Decoration::AlignmentId | Decoration::MaxByteOffsetId | Decoration::UniformId => true,
+        _ => false,
+    }
+}
+
 fn decoration_is_string(decoration: Decoration) -> bool {
     match decoration {
         Decoration::HlslSemanticGOOGLE | Decoration::UserTypeGOOGLE => true,
@@ -163,8 +323,39 @@ fn decoration_is_string(decoration: Decoration) -> bool {
     }
 }
 
+/// Memory access qualifiers of an image or buffer variable.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ImageAccess {
+    /// The variable can be read from, i.e. it is not decorated `NonReadable`.
+    pub read: bool,
+    /// The variable can be written to, i.e. it is not decorated `NonWritable`.
+    pub write: bool,
+    /// The variable is decorated `Coherent`.
+    pub coherent: bool,
+    /// The variable is decorated `Volatile`.
+    pub volatile: bool,
+    /// The variable is decorated `Restrict`.
+    pub restrict: bool,
+}
+
+/// Interpolation qualifiers of a stage input or output variable.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Interpolation {
+    /// The variable is decorated `Flat`.
+    pub flat: bool,
+    /// The variable is decorated `NoPerspective`.
+    pub no_perspective: bool,
+    /// The variable is decorated `Centroid`.
+    pub centroid: bool,
+    /// The variable is decorated `Sample`.
+    pub sample: bool,
+}
+
 impl<T> Compiler<T> {
     /// Gets the value for decorations which take arguments.
+    ///
+    /// The ID operand of [`AlignmentId`](Decoration::AlignmentId), [`MaxByteOffsetId`](Decoration::MaxByteOffsetId)
+    /// and [`UniformId`](Decoration::UniformId) is read from the SPIR-V binary the compiler was created from.
     pub fn decoration<I: Id>(
         &self,
         id: Handle<I>,
@@ -175,6 +366,36 @@ impl<T> Compiler<T> {
 
         // SAFETY: id is yielded by the instance so it's safe to use.
         let id = SpvId(self.yield_id(id)?.id());
+        self.decoration_by_id(id, decoration)
+    }
+
+    /// Gets all decorations that are set on an ID, along with their values.
+    ///
+    /// Every decoration known to the `spirv` crate is queried, and decorations which are not
+    /// set on the ID are skipped. This is mostly useful for debugging.
+    pub fn decorations<I: Id>(
+        &self,
+        id: Handle<I>,
+    ) -> error::Result<Vec<(Decoration, DecorationValue)>> {
+        // SAFETY: id is yielded by the instance so it's safe to use.
+        let id = SpvId(self.yield_id(id)?.id());
+
+        let mut decorations = Vec::new();
+        for &decoration in KNOWN_DECORATIONS {
+            if let Some(value) = self.decoration_by_id(id, decoration)? {
+                decorations.push((decoration, value));
+            }
+        }
+
+        Ok(decorations)
+    }
+
+    /// Gets the value of a decoration for an ID that was already yielded by this instance.
+    fn decoration_by_id(
+        &self,
+        id: SpvId,
+        decoration: Decoration,
+    ) -> error::Result<Option<DecorationValue>> {
         unsafe {
             let has_decoration = sys::spvc_compiler_has_decoration(
                 self.ptr.as_ptr(),
@@ -197,6 +418,22 @@ impl<T> Compiler<T> {
                 ))));
             }
 
+            if decoration_is_id(decoration) {
+                let mut operand = SpvId(0);
+                if !sys::spvc_rs_compiler_get_decoration_id_operand(
+                    self.ptr.as_ptr(),
+                    id,
+                    SpvDecoration(decoration as u32 as i32),
+                    &mut operand,
+                ) {
+                    return Err(SpirvCrossError::InvalidDecorationOutput(decoration, 0));
+                }
+
+                return Ok(Some(DecorationValue::Id(
+                    self.create_handle(ConstantId(operand)),
+                )));
+            }
+
             let value = sys::spvc_compiler_get_decoration(
                 self.ptr.as_ptr(),
                 id,
@@ -261,6 +498,10 @@ impl<T> Compiler<T> {
     }
 
     /// Set the value of a decoration for an ID.
+    ///
+    /// The ID operand of an `OpDecorateId` decoration can not be set, and passing a
+    /// [`DecorationValue::Id`] returns [`SpirvCrossError::InvalidOperation`]. Such decorations
+    /// can still be unset by passing `None`.
     pub fn set_decoration<'value, I: Id>(
         &mut self,
         id: Handle<I>,
@@ -269,6 +510,7 @@ impl<T> Compiler<T> {
     ) -> error::Result<()> {
         // SAFETY: id is yielded by the instance so it's safe to use.
         let id = SpvId(self.yield_id(id)?.id());
+        self.invalidate_shader_resources();
         unsafe {
             let Some(value) = value else {
                 sys::spvc_compiler_unset_decoration(
@@ -322,6 +564,11 @@ impl<T> Compiler<T> {
                         constant.id(),
                     );
                 }
+                DecorationValue::Id(_) => {
+                    return Err(SpirvCrossError::InvalidOperation(format!(
+                        "The ID operand of {decoration:?} can not be set"
+                    )));
+                }
                 DecorationValue::Present => {
                     sys::spvc_compiler_set_decoration(
                         self.ptr.as_ptr(),
@@ -385,6 +632,7 @@ impl<T> Compiler<T> {
         // SAFETY: id is yielded by the instance so it's safe to use.
         let struct_type = self.yield_id(struct_type)?;
 
+        self.invalidate_shader_resources();
         unsafe {
             let Some(value) = value else {
                 sys::spvc_compiler_unset_member_decoration(
@@ -443,6 +691,11 @@ impl<T> Compiler<T> {
                         constant.id(),
                     );
                 }
+                DecorationValue::Id(_) => {
+                    return Err(SpirvCrossError::InvalidOperation(format!(
+                        "The ID operand of {decoration:?} can not be set"
+                    )));
+                }
                 DecorationValue::Present => {
                     sys::spvc_compiler_set_member_decoration(
                         self.ptr.as_ptr(),
@@ -544,6 +797,11 @@ impl<T> Compiler<T> {
                     self.create_handle(ConstantId(SpvId(value))),
                 )))
             },
+            // ID decorations of IDs are read in decoration_by_id. SPIR-V has no
+            // OpMemberDecorateId, so an ID decoration on a member has no operand.
+            Decoration::AlignmentId | Decoration::MaxByteOffsetId | Decoration::UniformId => {
+                Err(SpirvCrossError::InvalidDecorationOutput(decoration, value))
+            }
             _ => {
                 if value == 1 {
                     Ok(Some(DecorationValue::Present))
@@ -554,6 +812,123 @@ impl<T> Compiler<T> {
         }
     }
 
+    /// Get the memory access qualifiers of a variable, i.e. a storage image.
+    ///
+    /// The qualifiers are read from the `NonReadable`, `NonWritable`, `Coherent`,
+    /// `Volatile` and `Restrict` decorations of the variable.
+    pub fn image_access_qualifiers(
+        &self,
+        variable: impl Into<Handle<VariableId>>,
+    ) -> error::Result<ImageAccess> {
+        let variable = variable.into();
+        let has_decoration = |decoration| -> error::Result<bool> {
+            Ok(self.decoration(variable, decoration)?.is_some())
+        };
+
+        Ok(ImageAccess {
+            read: !has_decoration(Decoration::NonReadable)?,
+            write: !has_decoration(Decoration::NonWritable)?,
+            coherent: has_decoration(Decoration::Coherent)?,
+            volatile: has_decoration(Decoration::Volatile)?,
+            restrict: has_decoration(Decoration::Restrict)?,
+        })
+    }
+
+    /// Get the interpolation qualifiers of a stage input or output variable.
+    ///
+    /// The qualifiers are read from the `Flat`, `NoPerspective`, `Centroid`
+    /// and `Sample` decorations of the variable.
+    pub fn interpolation_qualifiers(
+        &self,
+        variable: impl Into<Handle<VariableId>>,
+    ) -> error::Result<Interpolation> {
+        let variable = variable.into();
+        let has_decoration = |decoration| -> error::Result<bool> {
+            Ok(self.decoration(variable, decoration)?.is_some())
+        };
+
+        Ok(Interpolation {
+            flat: has_decoration(Decoration::Flat)?,
+            no_perspective: has_decoration(Decoration::NoPerspective)?,
+            centroid: has_decoration(Decoration::Centroid)?,
+            sample: has_decoration(Decoration::Sample)?,
+        })
+    }
+
+    /// Return whether the result of the instruction with the given result ID is decorated
+    /// `RelaxedPrecision`, i.e. it may be computed and stored with at least 16 bits of
+    /// precision (`mediump`).
+    ///
+    /// SPIRV-Cross keeps the decorations of every ID in the module, so this takes the raw
+    /// result ID of any instruction, such as an `OpFMul`, rather than a handle.
+    /// SPIRV-Cross does not infer precision for results that are not explicitly decorated,
+    /// so an undecorated result is reported as full precision.
+    ///
+    /// Returns [`SpirvCrossError::InvalidArgument`] if the ID is 0 or not below the
+    /// ID bound of the module.
+    pub fn operation_is_relaxed_precision(&self, id: u32) -> error::Result<bool> {
+        unsafe {
+            let bound = sys::spvc_compiler_get_current_id_bound(self.ptr.as_ptr());
+            if id == 0 || id >= bound {
+                return Err(SpirvCrossError::InvalidArgument(format!(
+                    "The result ID {id} is out of bounds for the ID bound {bound}."
+                )));
+            }
+
+            Ok(sys::spvc_compiler_has_decoration(
+                self.ptr.as_ptr(),
+                SpvId(id),
+                SpvDecoration(Decoration::RelaxedPrecision as u32 as i32),
+            ))
+        }
+    }
+
+    /// Get the `InputAttachmentIndex` of a subpass input variable.
+    ///
+    /// Returns `None` if the variable is not decorated with an input attachment index.
+    pub fn input_attachment_index(
+        &self,
+        variable: impl Into<Handle<VariableId>>,
+    ) -> error::Result<Option<u32>> {
+        Ok(self
+            .decoration(variable.into(), Decoration::InputAttachmentIndex)?
+            .and_then(|value| value.as_literal()))
+    }
+
+    /// Set the `InputAttachmentIndex` of a subpass input variable.
+    pub fn set_input_attachment_index(
+        &mut self,
+        variable: impl Into<Handle<VariableId>>,
+        index: u32,
+    ) -> error::Result<()> {
+        self.set_decoration(
+            variable.into(),
+            Decoration::InputAttachmentIndex,
+            Some(index),
+        )
+    }
+
+    /// Get the dual-source blending `Index` of a fragment output variable.
+    ///
+    /// Returns `None` if the variable is not decorated with an index, which is equivalent to index 0.
+    pub fn blend_index(
+        &self,
+        variable: impl Into<Handle<VariableId>>,
+    ) -> error::Result<Option<u32>> {
+        Ok(self
+            .decoration(variable.into(), Decoration::Index)?
+            .and_then(|value| value.as_literal()))
+    }
+
+    /// Set the dual-source blending `Index` of a fragment output variable.
+    pub fn set_blend_index(
+        &mut self,
+        variable: impl Into<Handle<VariableId>>,
+        index: u32,
+    ) -> error::Result<()> {
+        self.set_decoration(variable.into(), Decoration::Index, Some(index))
+    }
+
     /// Get the decorations for a buffer block resource.
     ///
     /// If the variable handle is not a handle to with struct
@@ -591,6 +966,7 @@ impl<T> Compiler<T> {
 #[cfg(test)]
 mod test {
     use crate::error::SpirvCrossError;
+    use crate::reflect::{DecorationValue, Interpolation};
     use crate::Compiler;
 
     use crate::{targets, Module};
@@ -602,10 +978,262 @@ mod test {
         let vec = Vec::from(BASIC_SPV);
         let words = Module::from_words(bytemuck::cast_slice(&vec));
 
+        let mut compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+        let output = resources.stage_outputs[0].id;
+
+        compiler.set_decoration(output, spirv::Decoration::HlslSemanticGOOGLE, Some("COLOR"))?;
+        assert_eq!(
+            Some(DecorationValue::from("COLOR")),
+            compiler.decoration(output, spirv::Decoration::HlslSemanticGOOGLE)?
+        );
+
+        compiler.set_decoration(
+            output,
+            spirv::Decoration::HlslSemanticGOOGLE,
+            None::<DecorationValue>,
+        )?;
+        assert_eq!(
+            None,
+            compiler.decoration(output, spirv::Decoration::HlslSemanticGOOGLE)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn set_decoration_without_target() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let mut compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+        let ubo = &resources.uniform_buffers[0];
+
+        compiler.set_decoration(ubo.id, spirv::Decoration::Binding, Some(4u32))?;
+        compiler.set_member_decoration_by_handle(
+            ubo.base_type_id,
+            0,
+            spirv::Decoration::Offset,
+            Some(16u32),
+        )?;
+
+        assert_eq!(
+            Some(DecorationValue::Literal(4)),
+            compiler.decoration(ubo.id, spirv::Decoration::Binding)?
+        );
+        assert_eq!(
+            Some(DecorationValue::Literal(16)),
+            compiler.member_decoration_by_handle(ubo.base_type_id, 0, spirv::Decoration::Offset)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn operation_is_relaxed_precision() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Fragment %main "main" %color
+            OpExecutionMode %main OriginUpperLeft
+            OpDecorate %color Location 0
+            OpDecorate %relaxed RelaxedPrecision
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %float = OpTypeFloat 32
+            %_ptr_Output_float = OpTypePointer Output %float
+            %color = OpVariable %_ptr_Output_float Output
+            %half = OpConstant %float 0.5
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            %relaxed = OpFMul %float %half %half
+            %full = OpFMul %float %relaxed %half
+            OpStore %color %full
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+
+        // Find the result IDs of the OpFMul instructions, in order.
+        let mut results = Vec::new();
+        let mut offset = 5;
+        while offset < words.len() {
+            let word_count = (words[offset] >> 16) as usize;
+            if words[offset] & 0xffff == spirv::Op::FMul as u32 {
+                results.push(words[offset + 2]);
+            }
+            offset += word_count;
+        }
+
+        let [relaxed, full] = results[..] else {
+            panic!("expected two OpFMul instructions");
+        };
+        let bound = words[3];
+
+        assert!(compiler.operation_is_relaxed_precision(relaxed)?);
+        assert!(!compiler.operation_is_relaxed_precision(full)?);
+
+        assert!(matches!(
+            compiler.operation_is_relaxed_precision(0),
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            compiler.operation_is_relaxed_precision(bound),
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn interpolation_qualifiers_test() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let mut compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+        let output = resources.stage_outputs[0].id;
+
+        assert_eq!(
+            Interpolation::default(),
+            compiler.interpolation_qualifiers(output)?
+        );
+
+        compiler.set_decoration(output, spirv::Decoration::Flat, Some(()))?;
+        assert!(compiler.interpolation_qualifiers(output)?.flat);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn set_decoration_invalidates_shader_resources() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let mut compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let cached = compiler.shader_resources()?.0;
+        assert_eq!(cached, compiler.shader_resources()?.0);
+
+        let ubo = compiler
+            .shader_resources()?
+            .all_resources()?
+            .uniform_buffers[0]
+            .id;
+        compiler.set_decoration(ubo, spirv::Decoration::Binding, Some(4u32))?;
+        assert!(compiler.shader_resources.get().is_none());
+        assert_ne!(cached, compiler.shader_resources()?.0);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn decorations_test() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
         let compiler: Compiler<targets::None> = Compiler::new(words)?;
         let resources = compiler.shader_resources()?.all_resources()?;
+        let ubo = &resources.uniform_buffers[0];
+
+        let decorations = compiler.decorations(ubo.id)?;
+        assert!(decorations.contains(&(
+            spirv::Decoration::DescriptorSet,
+            DecorationValue::Literal(0)
+        )));
+        assert!(decorations.contains(&(spirv::Decoration::Binding, DecorationValue::Literal(0))));
+        assert!(!decorations
+            .iter()
+            .any(|(decoration, _)| *decoration == spirv::Decoration::Location));
+        assert!(!decorations
+            .iter()
+            .any(|(_, value)| matches!(value, DecorationValue::Id(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn decoration_id_operand() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Fragment %main "main" %color
+            OpExecutionMode %main OriginUpperLeft
+            OpDecorate %color Location 0
+            OpDecorate %scope SpecId 0
+            OpDecorateId %color UniformId %scope
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %uint = OpTypeInt 32 0
+            %float = OpTypeFloat 32
+            %ptr = OpTypePointer Output %float
+            %color = OpVariable %ptr Output
+            %scope = OpSpecConstant %uint 3
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let mut compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+        let color = resources.stage_outputs[0].id;
+        let scope = compiler
+            .specialization_constants()?
+            .next()
+            .expect("spec constant is declared")
+            .id;
+
+        assert_eq!(
+            Some(DecorationValue::Id(scope)),
+            compiler.decoration(color, spirv::Decoration::UniformId)?
+        );
+        assert!(compiler
+            .decorations(color)?
+            .contains(&(spirv::Decoration::UniformId, DecorationValue::Id(scope))));
+
+        assert!(matches!(
+            compiler.set_decoration(
+                color,
+                spirv::Decoration::UniformId,
+                Some(DecorationValue::Id(scope))
+            ),
+            Err(SpirvCrossError::InvalidOperation(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn binary_offset_for_decoration() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words: &[u32] = bytemuck::cast_slice(&vec);
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(words))?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+        let ubo = resources.uniform_buffers[0].id;
+
+        let binding = compiler
+            .binary_offset_for_decoration(ubo, spirv::Decoration::Binding)?
+            .expect("binding is declared");
+        let set = compiler
+            .binary_offset_for_decoration(ubo, spirv::Decoration::DescriptorSet)?
+            .expect("descriptor set is declared");
+
+        assert_ne!(binding, set);
+        assert_eq!(0, words[binding as usize]);
+        assert_eq!(0, words[set as usize]);
+
+        assert_eq!(
+            None,
+            compiler.binary_offset_for_decoration(ubo, spirv::Decoration::Location)?
+        );
 
-        // compiler.set_decoration(Decoration::HlslSemanticGOOGLE, DecorationValue::String(Cow::Borrowed("hello")));
         Ok(())
     }
 }
@@ -3,9 +3,10 @@ use crate::error;
 use crate::error::{SpirvCrossError, ToContextError};
 use crate::handle::Handle;
 use crate::iter::impl_iterator;
-use crate::reflect::try_valid_slice;
+use crate::reflect::{try_valid_slice, BuiltinResourceType};
+use crate::sealed::Sealed;
 use crate::string::CompilerStr;
-use crate::Compiler;
+use crate::{Compiler, ToStatic};
 use core::slice;
 use spirv_cross_sys as sys;
 use spirv_cross_sys::{spvc_entry_point, SpvBuiltIn, SpvExecutionModel, SpvStorageClass};
@@ -37,6 +38,56 @@ impl<T> Compiler<T> {
         }
     }
 
+    /// Gets the source language and its version, as declared by the `OpSource` instruction
+    /// of the SPIR-V module.
+    ///
+    /// Returns `None` if the module did not declare a source language.
+    /// See [`Module::source_language`](crate::Module::source_language).
+    pub fn source_language(&self) -> Option<(spirv::SourceLanguage, u32)> {
+        self.source_language
+    }
+
+    /// Return whether the SPIR-V module declares any capability for subgroup operations.
+    ///
+    /// Subgroup builtins such as `SubgroupSize` and `SubgroupLocalInvocationId` also require
+    /// one of these capabilities, so they are covered by this check as well.
+    pub fn uses_subgroup_operations(&self) -> error::Result<bool> {
+        use spirv::Capability;
+
+        Ok(self.declared_capabilities()?.iter().any(|cap| {
+            matches!(
+                cap,
+                Capability::GroupNonUniform
+                    | Capability::GroupNonUniformVote
+                    | Capability::GroupNonUniformArithmetic
+                    | Capability::GroupNonUniformBallot
+                    | Capability::GroupNonUniformShuffle
+                    | Capability::GroupNonUniformShuffleRelative
+                    | Capability::GroupNonUniformClustered
+                    | Capability::GroupNonUniformQuad
+                    | Capability::GroupNonUniformPartitionedNV
+                    | Capability::SubgroupBallotKHR
+                    | Capability::SubgroupVoteKHR
+            )
+        }))
+    }
+
+    /// Return whether the shader uses multiview rendering, i.e. it declares the `MultiView`
+    /// capability or reads the `ViewIndex` built-in.
+    pub fn uses_multiview(&self) -> error::Result<bool> {
+        if self
+            .declared_capabilities()?
+            .contains(&spirv::Capability::MultiView)
+        {
+            return Ok(true);
+        }
+
+        Ok(self
+            .shader_resources()?
+            .builtin_resources_for_type(BuiltinResourceType::StageInput)?
+            .any(|input| input.builtin == spirv::BuiltIn::ViewIndex))
+    }
+
     /// Gets the list of all SPIR-V extensions which were declared in the SPIR-V module.
     pub fn declared_extensions(&self) -> error::Result<ExtensionsIter<'static>> {
         // SAFETY: 'a is OK to return here
@@ -106,6 +157,39 @@ impl<T> Compiler<T> {
             ))
         }
     }
+
+    /// Return whether the fragment shader writes to `FragDepth`.
+    ///
+    /// Requires [`Compiler::update_active_builtins`] to be called first,
+    /// proof of which is required to call this function.
+    pub fn fragment_writes_depth(&self, proof: ActiveBuiltinsUpdatedProof) -> error::Result<bool> {
+        self.has_active_builtin(
+            spirv::BuiltIn::FragDepth,
+            spirv::StorageClass::Output,
+            proof,
+        )
+    }
+
+    /// Return whether the shader writes to `Position`.
+    ///
+    /// For multiview rendering, this together with [`Compiler::uses_multiview`] tells
+    /// whether the position output can differ per view.
+    ///
+    /// Requires [`Compiler::update_active_builtins`] to be called first,
+    /// proof of which is required to call this function.
+    pub fn writes_position(&self, proof: ActiveBuiltinsUpdatedProof) -> error::Result<bool> {
+        self.has_active_builtin(spirv::BuiltIn::Position, spirv::StorageClass::Output, proof)
+    }
+
+    /// Return whether the current entry point can discard fragments, either with
+    /// `OpKill`, `OpTerminateInvocation`, or `OpDemoteToHelperInvocation`.
+    pub fn has_discard(&self) -> error::Result<bool> {
+        unsafe {
+            let mut has_discard = false;
+            sys::spvc_rs_compiler_has_discard(self.ptr.as_ptr(), &mut has_discard).ok(self)?;
+            Ok(has_discard)
+        }
+    }
 }
 
 /// Iterator type created by [`Compiler::entry_points`].
@@ -120,6 +204,21 @@ pub struct EntryPoint<'a> {
     pub name: CompilerStr<'a>,
 }
 
+impl Sealed for EntryPoint<'_> {}
+impl ToStatic for EntryPoint<'_> {
+    type Static<'a>
+    = EntryPoint<'static>
+    where
+        'a: 'static;
+
+    fn to_static(&self) -> Self::Static<'static> {
+        EntryPoint {
+            execution_model: self.execution_model,
+            name: self.name.to_static(),
+        }
+    }
+}
+
 impl_iterator!(EntryPointIter<'a>: EntryPoint<'a> as and_then|s, entry: &spvc_entry_point| {
     unsafe {
         let Some(execution_model) = spirv::ExecutionModel::from_u32(entry.execution_model.0 as u32) else {
@@ -179,6 +278,12 @@ impl<T> Compiler<T> {
     }
 
     /// Get the cleansed name of the entry point for the given original name.
+    ///
+    /// After compilation, this is the name of the entry point as it appears in the
+    /// emitted source, which may differ from the original name if it is illegal in the
+    /// target language. Before compilation, the original name is returned.
+    ///
+    /// Returns `None` if there is no entry point with the given name and execution model.
     pub fn cleansed_entry_point_name<'str>(
         &self,
         name: impl Into<CompilerStr<'str>>,
@@ -227,6 +332,7 @@ impl<T> Compiler<T> {
         model: spirv::ExecutionModel,
     ) -> error::Result<()> {
         let name = name.into();
+        self.invalidate_shader_resources();
         unsafe {
             let name = name.into_cstring_ptr()?;
 
@@ -278,6 +384,34 @@ mod test {
 
     static BASIC_SPV: &[u8] = include_bytes!("../../basic.spv");
 
+    /// A fragment shader that discards behind a branch, and otherwise writes `FragDepth`.
+    static DISCARD_DEPTH_ASM: &str = r#"
+        OpCapability Shader
+        OpMemoryModel Logical GLSL450
+        OpEntryPoint Fragment %main "main" %depth
+        OpExecutionMode %main OriginUpperLeft
+        OpExecutionMode %main DepthReplacing
+        OpDecorate %depth BuiltIn FragDepth
+        %void = OpTypeVoid
+        %fn = OpTypeFunction %void
+        %bool = OpTypeBool
+        %true = OpConstantTrue %bool
+        %float = OpTypeFloat 32
+        %float_0_5 = OpConstant %float 0.5
+        %_ptr_Output_float = OpTypePointer Output %float
+        %depth = OpVariable %_ptr_Output_float Output
+        %main = OpFunction %void None %fn
+        %entry = OpLabel
+        OpSelectionMerge %merge None
+        OpBranchConditional %true %discard %merge
+        %discard = OpLabel
+        OpKill
+        %merge = OpLabel
+        OpStore %depth %float_0_5
+        OpReturn
+        OpFunctionEnd
+        "#;
+
     #[test]
     pub fn get_entry_points() -> Result<(), SpirvCrossError> {
         let vec = Vec::from(BASIC_SPV);
@@ -342,4 +476,154 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    pub fn uses_subgroup_operations() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+
+        assert!(!compiler.uses_subgroup_operations()?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn uses_multiview() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+
+        assert!(!compiler.uses_multiview()?);
+
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpCapability MultiView
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Vertex %main "main" %view_index %position
+            OpDecorate %view_index BuiltIn ViewIndex
+            OpDecorate %position BuiltIn Position
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %uint = OpTypeInt 32 0
+            %float = OpTypeFloat 32
+            %v4float = OpTypeVector %float 4
+            %_ptr_Input_uint = OpTypePointer Input %uint
+            %_ptr_Output_v4float = OpTypePointer Output %v4float
+            %view_index = OpVariable %_ptr_Input_uint Input
+            %position = OpVariable %_ptr_Output_v4float Output
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            %view = OpLoad %uint %view_index
+            %x = OpConvertUToF %float %view
+            %value = OpCompositeConstruct %v4float %x %x %x %x
+            OpStore %position %value
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let mut compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        assert!(compiler.uses_multiview()?);
+
+        let proof = compiler.update_active_builtins();
+        assert!(compiler.has_active_builtin(
+            spirv::BuiltIn::ViewIndex,
+            spirv::StorageClass::Input,
+            proof
+        )?);
+        assert!(compiler.writes_position(proof)?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn writes_position() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let mut compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let proof = compiler.update_active_builtins();
+
+        // Fragment shaders do not write the position.
+        assert!(!compiler.writes_position(proof)?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn fragment_writes_depth() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let mut compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let proof = compiler.update_active_builtins();
+        assert!(!compiler.fragment_writes_depth(proof)?);
+
+        let words = Module::from_spirv_asm(DISCARD_DEPTH_ASM)?;
+        let mut compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        let proof = compiler.update_active_builtins();
+        assert!(compiler.fragment_writes_depth(proof)?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn has_discard() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        assert!(!compiler.has_discard()?);
+
+        let words = Module::from_spirv_asm(DISCARD_DEPTH_ASM)?;
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        assert!(compiler.has_discard()?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn set_entry_point() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let mut compiler: Compiler<targets::None> = Compiler::new(words)?;
+        compiler.set_entry_point("main", ExecutionModel::Fragment)?;
+        assert_eq!(ExecutionModel::Fragment, compiler.execution_model()?);
+
+        assert!(compiler
+            .set_entry_point("main", ExecutionModel::Vertex)
+            .is_err());
+
+        compiler.rename_entry_point("main", "frag_main", ExecutionModel::Fragment)?;
+        compiler.set_entry_point("frag_main", ExecutionModel::Fragment)?;
+
+        let entry_points: Vec<_> = compiler.entry_points()?.collect();
+        assert_eq!(1, entry_points.len());
+        assert_eq!("frag_main", entry_points[0].name.as_ref());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn source_language() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+        assert_eq!(
+            Some((spirv::SourceLanguage::GLSL, 450)),
+            words.source_language()
+        );
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        assert_eq!(
+            Some((spirv::SourceLanguage::GLSL, 450)),
+            compiler.source_language()
+        );
+
+        Ok(())
+    }
 }
@@ -1,6 +1,6 @@
 use crate::error;
-use crate::error::ToContextError;
-use crate::handle::Handle;
+use crate::error::{SpirvCrossError, ToContextError};
+use crate::handle::{Handle, Id};
 use crate::reflect::try_valid_slice;
 use crate::Compiler;
 use spirv_cross_sys as sys;
@@ -35,15 +35,96 @@ pub enum ExecutionModeArguments {
     },
 }
 
-impl ExecutionModeArguments {
-    fn expand(self) -> [u32; 3] {
-        match self {
-            ExecutionModeArguments::None => [0, 0, 0],
-            ExecutionModeArguments::Literal(a) => [a, 0, 0],
-            ExecutionModeArguments::LocalSize { x, y, z } => [x, y, z],
-            ExecutionModeArguments::LocalSizeId { x, y, z } => [x.id(), y.id(), z.id()],
-        }
-    }
+/// The primitive generated by the tessellator.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TessellationPrimitive {
+    /// The `Triangles` execution mode.
+    Triangles,
+    /// The `Quads` execution mode.
+    Quads,
+    /// The `Isolines` execution mode.
+    Isolines,
+}
+
+/// The spacing of segments generated by the tessellator.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TessellationSpacing {
+    /// The `SpacingEqual` execution mode.
+    Equal,
+    /// The `SpacingFractionalEven` execution mode.
+    FractionalEven,
+    /// The `SpacingFractionalOdd` execution mode.
+    FractionalOdd,
+}
+
+/// The winding order of triangles generated by the tessellator.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TessellationWinding {
+    /// The `VertexOrderCw` execution mode.
+    Clockwise,
+    /// The `VertexOrderCcw` execution mode.
+    CounterClockwise,
+}
+
+/// Tessellation execution modes of a tessellation control or evaluation shader,
+/// returned by [`Compiler::tessellation_info`].
+///
+/// Execution modes may be declared in either tessellation stage,
+/// so modes that are not declared by the current shader are `None`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TessellationInfo {
+    /// The number of vertices in the output patch, from the `OutputVertices` execution mode.
+    pub output_vertices: Option<u32>,
+    /// The primitive generated by the tessellator.
+    pub primitive_mode: Option<TessellationPrimitive>,
+    /// The spacing of segments generated by the tessellator.
+    pub spacing: Option<TessellationSpacing>,
+    /// The winding order of triangles generated by the tessellator.
+    pub winding: Option<TessellationWinding>,
+    /// Whether the tessellator generates points, from the `PointMode` execution mode.
+    pub point_mode: bool,
+}
+
+/// The input primitive of a geometry shader.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum GeometryInputPrimitive {
+    /// The `InputPoints` execution mode.
+    Points,
+    /// The `InputLines` execution mode.
+    Lines,
+    /// The `InputLinesAdjacency` execution mode.
+    LinesAdjacency,
+    /// The `Triangles` execution mode.
+    Triangles,
+    /// The `InputTrianglesAdjacency` execution mode.
+    TrianglesAdjacency,
+}
+
+/// The output primitive of a geometry shader.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum GeometryOutputPrimitive {
+    /// The `OutputPoints` execution mode.
+    Points,
+    /// The `OutputLineStrip` execution mode.
+    LineStrip,
+    /// The `OutputTriangleStrip` execution mode.
+    TriangleStrip,
+}
+
+/// Execution modes of a geometry shader, returned by [`Compiler::geometry_info`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct GeometryInfo {
+    /// The input primitive of the geometry shader.
+    pub input_primitive: Option<GeometryInputPrimitive>,
+    /// The output primitive of the geometry shader.
+    pub output_primitive: Option<GeometryOutputPrimitive>,
+    /// The maximum number of vertices emitted, from the `OutputVertices` execution mode.
+    pub max_output_vertices: u32,
+    /// The number of invocations of the geometry shader per input primitive,
+    /// from the `Invocations` execution mode.
+    ///
+    /// If the execution mode is not declared, the shader is invoked once.
+    pub invocations: u32,
 }
 
 impl<T> Compiler<T> {
@@ -51,21 +132,36 @@ impl<T> Compiler<T> {
     ///
     /// If arguments is `None`, unsets the execution mode. To set an execution mode that does not
     /// take arguments, pass `Some(ExecutionModeArguments::None)`.
+    ///
+    /// Returns [`SpirvCrossError::InvalidHandle`] if the handles of
+    /// [`ExecutionModeArguments::LocalSizeId`] did not originate from this compiler instance.
     pub fn set_execution_mode(
         &mut self,
         mode: spirv::ExecutionMode,
         arguments: Option<ExecutionModeArguments>,
-    ) {
-        unsafe {
-            let Some(arguments) = arguments else {
-                return sys::spvc_compiler_unset_execution_mode(
+    ) -> error::Result<()> {
+        let Some(arguments) = arguments else {
+            unsafe {
+                sys::spvc_compiler_unset_execution_mode(
                     self.ptr.as_ptr(),
                     SpvExecutionMode(mode as u32 as i32),
                 );
-            };
+            }
+            return Ok(());
+        };
 
-            let [x, y, z] = arguments.expand();
+        let [x, y, z] = match arguments {
+            ExecutionModeArguments::None => [0, 0, 0],
+            ExecutionModeArguments::Literal(a) => [a, 0, 0],
+            ExecutionModeArguments::LocalSize { x, y, z } => [x, y, z],
+            ExecutionModeArguments::LocalSizeId { x, y, z } => [
+                self.yield_id(x)?.id(),
+                self.yield_id(y)?.id(),
+                self.yield_id(z)?.id(),
+            ],
+        };
 
+        unsafe {
             sys::spvc_compiler_set_execution_mode_with_arguments(
                 self.ptr.as_ptr(),
                 SpvExecutionMode(mode as u32 as i32),
@@ -74,6 +170,8 @@ impl<T> Compiler<T> {
                 z,
             );
         }
+
+        Ok(())
     }
 
     /// Query `OpExecutionMode`.
@@ -179,11 +277,151 @@ impl<T> Compiler<T> {
             }
         })
     }
+
+    /// Get the work group size of a compute-like shader, with any dimensions that are
+    /// specialization constants resolved to their current values.
+    ///
+    /// Dimensions declared with `LocalSizeId` are resolved from their constants. Otherwise,
+    /// the `LocalSize` literals are used, unless overridden by a specialization constant
+    /// in the `WorkgroupSize` built-in. See [`Compiler::work_group_size_specialization_constants`].
+    ///
+    /// If the shader does not declare a work group size, returns [`SpirvCrossError::InvalidOperation`].
+    pub fn work_group_size_resolved(&self) -> error::Result<[u32; 3]> {
+        if let Some(ExecutionModeArguments::LocalSizeId { x, y, z }) =
+            self.execution_mode_arguments(spirv::ExecutionMode::LocalSizeId)?
+        {
+            return Ok([
+                self.specialization_constant_value::<u32>(x)?,
+                self.specialization_constant_value::<u32>(y)?,
+                self.specialization_constant_value::<u32>(z)?,
+            ]);
+        }
+
+        let Some(ExecutionModeArguments::LocalSize { x, y, z }) =
+            self.execution_mode_arguments(spirv::ExecutionMode::LocalSize)?
+        else {
+            return Err(SpirvCrossError::InvalidOperation(String::from(
+                "The shader does not declare a work group size",
+            )));
+        };
+
+        let mut size = [x, y, z];
+        let constants = self.work_group_size_specialization_constants();
+        for (dimension, constant) in size.iter_mut().zip([constants.x, constants.y, constants.z]) {
+            if let Some(constant) = constant {
+                *dimension = self.specialization_constant_value::<u32>(constant.id)?;
+            }
+        }
+
+        Ok(size)
+    }
+
+    /// Get the tessellation execution modes of the shader.
+    ///
+    /// Returns `None` if the current entry point is not a tessellation control
+    /// or tessellation evaluation shader.
+    pub fn tessellation_info(&self) -> error::Result<Option<TessellationInfo>> {
+        if !matches!(
+            self.execution_model()?,
+            spirv::ExecutionModel::TessellationControl
+                | spirv::ExecutionModel::TessellationEvaluation
+        ) {
+            return Ok(None);
+        }
+
+        let modes = self.execution_modes()?;
+        let output_vertices =
+            match self.execution_mode_arguments(spirv::ExecutionMode::OutputVertices)? {
+                Some(ExecutionModeArguments::Literal(vertices)) => Some(vertices),
+                _ => None,
+            };
+
+        let primitive_mode = modes.iter().find_map(|mode| match mode {
+            spirv::ExecutionMode::Triangles => Some(TessellationPrimitive::Triangles),
+            spirv::ExecutionMode::Quads => Some(TessellationPrimitive::Quads),
+            spirv::ExecutionMode::Isolines => Some(TessellationPrimitive::Isolines),
+            _ => None,
+        });
+
+        let spacing = modes.iter().find_map(|mode| match mode {
+            spirv::ExecutionMode::SpacingEqual => Some(TessellationSpacing::Equal),
+            spirv::ExecutionMode::SpacingFractionalEven => {
+                Some(TessellationSpacing::FractionalEven)
+            }
+            spirv::ExecutionMode::SpacingFractionalOdd => Some(TessellationSpacing::FractionalOdd),
+            _ => None,
+        });
+
+        let winding = modes.iter().find_map(|mode| match mode {
+            spirv::ExecutionMode::VertexOrderCw => Some(TessellationWinding::Clockwise),
+            spirv::ExecutionMode::VertexOrderCcw => Some(TessellationWinding::CounterClockwise),
+            _ => None,
+        });
+
+        Ok(Some(TessellationInfo {
+            output_vertices,
+            primitive_mode,
+            spacing,
+            winding,
+            point_mode: modes.contains(&spirv::ExecutionMode::PointMode),
+        }))
+    }
+
+    /// Get the input and output primitives of a geometry shader.
+    ///
+    /// Returns `None` if the current entry point is not a geometry shader.
+    pub fn geometry_info(&self) -> error::Result<Option<GeometryInfo>> {
+        if self.execution_model()? != spirv::ExecutionModel::Geometry {
+            return Ok(None);
+        }
+
+        let modes = self.execution_modes()?;
+        let literal = |mode| -> error::Result<Option<u32>> {
+            Ok(match self.execution_mode_arguments(mode)? {
+                Some(ExecutionModeArguments::Literal(value)) => Some(value),
+                _ => None,
+            })
+        };
+
+        let input_primitive = modes.iter().find_map(|mode| match mode {
+            spirv::ExecutionMode::InputPoints => Some(GeometryInputPrimitive::Points),
+            spirv::ExecutionMode::InputLines => Some(GeometryInputPrimitive::Lines),
+            spirv::ExecutionMode::InputLinesAdjacency => {
+                Some(GeometryInputPrimitive::LinesAdjacency)
+            }
+            spirv::ExecutionMode::Triangles => Some(GeometryInputPrimitive::Triangles),
+            spirv::ExecutionMode::InputTrianglesAdjacency => {
+                Some(GeometryInputPrimitive::TrianglesAdjacency)
+            }
+            _ => None,
+        });
+
+        let output_primitive = modes.iter().find_map(|mode| match mode {
+            spirv::ExecutionMode::OutputPoints => Some(GeometryOutputPrimitive::Points),
+            spirv::ExecutionMode::OutputLineStrip => Some(GeometryOutputPrimitive::LineStrip),
+            spirv::ExecutionMode::OutputTriangleStrip => {
+                Some(GeometryOutputPrimitive::TriangleStrip)
+            }
+            _ => None,
+        });
+
+        Ok(Some(GeometryInfo {
+            input_primitive,
+            output_primitive,
+            max_output_vertices: literal(spirv::ExecutionMode::OutputVertices)?.unwrap_or(0),
+            invocations: literal(spirv::ExecutionMode::Invocations)?.unwrap_or(1),
+        }))
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::error::SpirvCrossError;
+    use crate::handle::ConstantId;
+    use crate::reflect::{
+        ExecutionModeArguments, GeometryInfo, GeometryInputPrimitive, GeometryOutputPrimitive,
+        TessellationInfo, TessellationPrimitive, TessellationSpacing, TessellationWinding,
+    };
     use crate::Compiler;
     use crate::{targets, Module};
 
@@ -202,4 +440,274 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    pub fn execution_modes_multiple() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Fragment %main "main"
+            OpExecutionMode %main OriginUpperLeft
+            OpExecutionMode %main EarlyFragmentTests
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+
+        let mut modes = compiler.execution_modes()?.to_vec();
+        modes.sort_by_key(|mode| *mode as u32);
+        assert_eq!(
+            [
+                spirv::ExecutionMode::OriginUpperLeft,
+                spirv::ExecutionMode::EarlyFragmentTests
+            ],
+            modes.as_slice()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn set_execution_mode_foreign_handle() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+
+        let mut compiler: Compiler<targets::None> =
+            Compiler::new(Module::from_words(bytemuck::cast_slice(&vec)))?;
+        let other: Compiler<targets::None> =
+            Compiler::new(Module::from_words(bytemuck::cast_slice(&vec)))?;
+
+        let foreign = unsafe { other.create_handle(ConstantId::from(1)) };
+        let result = compiler.set_execution_mode(
+            spirv::ExecutionMode::LocalSizeId,
+            Some(ExecutionModeArguments::LocalSizeId {
+                x: foreign,
+                y: foreign,
+                z: foreign,
+            }),
+        );
+        assert!(matches!(result, Err(SpirvCrossError::InvalidHandle(_))));
+
+        compiler.set_execution_mode(spirv::ExecutionMode::OriginUpperLeft, None)?;
+        assert!(compiler.execution_modes()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn work_group_size_resolved() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+
+        // Fragment shaders do not have a work group size.
+        assert!(matches!(
+            compiler.work_group_size_resolved(),
+            Err(SpirvCrossError::InvalidOperation(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn work_group_size_resolved_local_size_id() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint GLCompute %main "main"
+            OpExecutionModeId %main LocalSizeId %x %y %z
+            OpDecorate %x SpecId 0
+            OpDecorate %y SpecId 1
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %uint = OpTypeInt 32 0
+            %x = OpSpecConstant %uint 8
+            %y = OpSpecConstant %uint 4
+            %z = OpConstant %uint 2
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let mut compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        assert_eq!([8, 4, 2], compiler.work_group_size_resolved()?);
+
+        let y = compiler
+            .specialization_constants()?
+            .find(|constant| constant.constant_id == 1)
+            .expect("y is a specialization constant")
+            .id;
+        compiler.set_specialization_constant_value(y, 16u32)?;
+        assert_eq!([8, 16, 2], compiler.work_group_size_resolved()?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn work_group_size_resolved_workgroup_size_builtin() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint GLCompute %main "main"
+            OpExecutionMode %main LocalSize 1 1 1
+            OpDecorate %x SpecId 0
+            OpDecorate %size BuiltIn WorkgroupSize
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %uint = OpTypeInt 32 0
+            %v3uint = OpTypeVector %uint 3
+            %x = OpSpecConstant %uint 64
+            %uint_1 = OpConstant %uint 1
+            %size = OpSpecConstantComposite %v3uint %x %uint_1 %uint_1
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+
+        // The x dimension is overridden by the specialization constant.
+        assert_eq!([64, 1, 1], compiler.work_group_size_resolved()?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn tessellation_info() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        assert_eq!(None, compiler.tessellation_info()?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn tessellation_info_evaluation() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Tessellation
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint TessellationEvaluation %main "main"
+            OpExecutionMode %main Quads
+            OpExecutionMode %main SpacingFractionalOdd
+            OpExecutionMode %main VertexOrderCw
+            OpExecutionMode %main PointMode
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        assert_eq!(
+            Some(TessellationInfo {
+                output_vertices: None,
+                primitive_mode: Some(TessellationPrimitive::Quads),
+                spacing: Some(TessellationSpacing::FractionalOdd),
+                winding: Some(TessellationWinding::Clockwise),
+                point_mode: true,
+            }),
+            compiler.tessellation_info()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn tessellation_info_control() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Tessellation
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint TessellationControl %main "main"
+            OpExecutionMode %main OutputVertices 3
+            OpExecutionMode %main Triangles
+            OpExecutionMode %main SpacingEqual
+            OpExecutionMode %main VertexOrderCcw
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        assert_eq!(
+            Some(TessellationInfo {
+                output_vertices: Some(3),
+                primitive_mode: Some(TessellationPrimitive::Triangles),
+                spacing: Some(TessellationSpacing::Equal),
+                winding: Some(TessellationWinding::CounterClockwise),
+                point_mode: false,
+            }),
+            compiler.tessellation_info()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn geometry_info() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        assert_eq!(None, compiler.geometry_info()?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn geometry_info_geometry_shader() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Geometry
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Geometry %main "main"
+            OpExecutionMode %main InputTrianglesAdjacency
+            OpExecutionMode %main OutputLineStrip
+            OpExecutionMode %main OutputVertices 6
+            OpExecutionMode %main Invocations 2
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        assert_eq!(
+            Some(GeometryInfo {
+                input_primitive: Some(GeometryInputPrimitive::TrianglesAdjacency),
+                output_primitive: Some(GeometryOutputPrimitive::LineStrip),
+                max_output_vertices: 6,
+                invocations: 2,
+            }),
+            compiler.geometry_info()?
+        );
+
+        Ok(())
+    }
 }
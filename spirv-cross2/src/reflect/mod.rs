@@ -5,9 +5,15 @@ mod decorations;
 mod entry_points;
 mod execution_modes;
 mod names;
+mod owned;
 mod resources;
 mod types;
 
+/// Vulkan descriptor set layout reflection.
+#[cfg(feature = "vulkan")]
+#[cfg_attr(docsrs, doc(cfg(feature = "vulkan")))]
+pub mod vulkan;
+
 use crate::{error, SpirvCrossError};
 pub use buffers::*;
 pub use combined_image_samplers::*;
@@ -15,6 +21,7 @@ pub use constants::*;
 pub use decorations::*;
 pub use entry_points::*;
 pub use execution_modes::*;
+pub use owned::*;
 pub use resources::*;
 pub use types::*;
 
@@ -8,6 +8,9 @@ use spirv_cross_sys::{SpvId, TypeId, VariableId};
 
 impl<T> Compiler<T> {
     /// Gets the identifier (`OpName`) of an ID.
+    ///
+    /// The returned string borrows from the compiler, since the name may be
+    /// changed with [`Compiler::set_name`]. Returns `None` if the ID has no name.
     pub fn name<I: Id>(&self, handle: Handle<I>) -> error::Result<Option<CompilerStr>> {
         let id = self.yield_id(handle)?;
         unsafe {
@@ -23,6 +26,8 @@ impl<T> Compiler<T> {
 
     /// Overrides the identifier OpName of an ID.
     ///
+    /// The new name can be read back with [`Compiler::name`].
+    ///
     /// Identifiers beginning with underscores or identifiers which contain double underscores
     /// are reserved by the implementation.
     pub fn set_name<'str, I: Id>(
@@ -33,6 +38,7 @@ impl<T> Compiler<T> {
         let id = self.yield_id(handle)?;
         let string = string.into();
 
+        self.invalidate_shader_resources();
         unsafe {
             let cstring = string.into_cstring_ptr()?;
 
@@ -47,6 +53,9 @@ impl<T> Compiler<T> {
     }
 
     /// Given a struct type ID, obtain the identifier for member number "index".
+    ///
+    /// The returned string borrows from the compiler, since the name may be
+    /// changed with [`Compiler::set_member_name`]. Returns `None` if the member has no name.
     pub fn member_name(
         &self,
         struct_type: Handle<TypeId>,
@@ -67,6 +76,8 @@ impl<T> Compiler<T> {
     }
 
     /// Sets the member identifier for the given struct member.
+    ///
+    /// The new name can be read back with [`Compiler::member_name`].
     pub fn set_member_name<'str>(
         &mut self,
         struct_type: Handle<TypeId>,
@@ -0,0 +1,143 @@
+use crate::error;
+use crate::handle::{Handle, TypeId};
+use crate::reflect::{
+    AllResources, EntryPoint, ExecutionModeArguments, SpecializationConstant, Type, TypeInner,
+};
+use crate::{Compiler, ToStatic};
+use std::collections::HashSet;
+
+/// Reflection data of a module that is fully detached from its compiler instance.
+///
+/// Unlike reflection results returned directly from a [`Compiler`], none of the
+/// strings in an `OwnedReflection` keep the SPIRV-Cross context alive, so the compiler
+/// can be dropped to free its memory while keeping the reflection data.
+///
+/// Handles contained in the reflection data remain tagged with the compiler instance
+/// they were created from, and can not be used with any other instance.
+#[derive(Debug)]
+pub struct OwnedReflection {
+    /// All resources declared in the module.
+    pub resources: AllResources<'static>,
+    /// The types of all resources, including pointed-to, array element, and struct member types.
+    pub types: Vec<Type<'static>>,
+    /// All entry points declared in the module.
+    pub entry_points: Vec<EntryPoint<'static>>,
+    /// The execution modes of the current entry point, and their arguments.
+    pub execution_modes: Vec<(spirv::ExecutionMode, ExecutionModeArguments)>,
+    /// All specialization constants declared in the module.
+    pub specialization_constants: Vec<SpecializationConstant>,
+}
+
+impl OwnedReflection {
+    /// Find the description of a type by its handle.
+    pub fn type_description(&self, id: Handle<TypeId>) -> Option<&Type<'static>> {
+        self.types.iter().find(|ty| ty.id == id)
+    }
+}
+
+impl<T> Compiler<T> {
+    /// Reflect all resources, types, entry points, execution modes and
+    /// specialization constants into owned data that does not borrow from the compiler.
+    pub fn reflect_owned(&self) -> error::Result<OwnedReflection> {
+        let resources = self.shader_resources()?.all_resources()?.to_static();
+
+        let mut seen = HashSet::new();
+        let mut types = Vec::new();
+        let mut queue = Vec::new();
+        for resource in [
+            &resources.uniform_buffers,
+            &resources.storage_buffers,
+            &resources.stage_inputs,
+            &resources.stage_outputs,
+            &resources.subpass_inputs,
+            &resources.storage_images,
+            &resources.sampled_images,
+            &resources.atomic_counters,
+            &resources.acceleration_structures,
+            &resources.gl_plain_uniforms,
+            &resources.push_constant_buffers,
+            &resources.shader_record_buffers,
+            &resources.separate_images,
+            &resources.separate_samplers,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            queue.push(resource.type_id);
+            queue.push(resource.base_type_id);
+        }
+
+        for builtin in resources
+            .builtin_inputs
+            .iter()
+            .chain(&resources.builtin_outputs)
+        {
+            queue.push(builtin.value_type_id);
+            queue.push(builtin.resource.type_id);
+            queue.push(builtin.resource.base_type_id);
+        }
+
+        while let Some(id) = queue.pop() {
+            if !seen.insert(id.id()) {
+                continue;
+            }
+
+            let ty = self.type_description(id)?;
+            match &ty.inner {
+                TypeInner::Pointer { base, .. } | TypeInner::Array { base, .. } => {
+                    queue.push(*base)
+                }
+                TypeInner::Struct(s) => queue.extend(s.members.iter().map(|member| member.id)),
+                TypeInner::Image(image) => queue.push(image.sampled_type),
+                _ => {}
+            }
+            types.push(ty.to_static());
+        }
+
+        let entry_points = self
+            .entry_points()?
+            .map(|entry_point| entry_point.to_static())
+            .collect();
+
+        let mut execution_modes = Vec::new();
+        for mode in self.execution_modes()? {
+            if let Some(arguments) = self.execution_mode_arguments(*mode)? {
+                execution_modes.push((*mode, arguments));
+            }
+        }
+
+        let specialization_constants = self.specialization_constants()?.collect();
+
+        Ok(OwnedReflection {
+            resources,
+            types,
+            entry_points,
+            execution_modes,
+            specialization_constants,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::SpirvCrossError;
+    use crate::Compiler;
+    use crate::{targets, Module};
+
+    static BASIC_SPV: &[u8] = include_bytes!("../../basic.spv");
+
+    #[test]
+    pub fn reflect_owned() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let reflection = compiler.reflect_owned()?;
+        drop(compiler);
+
+        let ubo = &reflection.resources.uniform_buffers[0];
+        assert!(reflection.type_description(ubo.base_type_id).is_some());
+        assert_eq!(1, reflection.entry_points.len());
+        Ok(())
+    }
+}
@@ -1,12 +1,15 @@
 use crate::error::{SpirvCrossError, ToContextError};
-use crate::handle::{Handle, TypeId, VariableId};
+use crate::handle::{ConstantId, Handle, TypeId, VariableId};
+use crate::reflect::{ArrayDimension, DecorationValue, TypeInner};
 use crate::sealed::Sealed;
 use crate::string::CompilerStr;
 use crate::{error, Compiler, PhantomCompiler, ToStatic};
 use spirv_cross_sys as sys;
 use spirv_cross_sys::{
     spvc_reflected_builtin_resource, spvc_reflected_resource, spvc_resources_s, spvc_set,
+    SpvDecoration, SpvId,
 };
+use std::fmt::{Display, Formatter};
 use std::ptr::NonNull;
 use std::slice;
 
@@ -17,16 +20,55 @@ use crate::iter::impl_iterator;
 /// The type of resource to query.
 pub use spirv_cross_sys::ResourceType;
 
+/// Resource types that are bound through descriptor sets.
+const DESCRIPTOR_TYPES: [ResourceType; 9] = [
+    ResourceType::UniformBuffer,
+    ResourceType::StorageBuffer,
+    ResourceType::SubpassInput,
+    ResourceType::StorageImage,
+    ResourceType::SampledImage,
+    ResourceType::AtomicCounter,
+    ResourceType::AccelerationStructure,
+    ResourceType::SeparateImage,
+    ResourceType::SeparateSamplers,
+];
+
+/// All resource types, in the same order as the fields of [`AllResources`].
+const RESOURCE_TYPES: [ResourceType; 14] = [
+    ResourceType::UniformBuffer,
+    ResourceType::StorageBuffer,
+    ResourceType::StageInput,
+    ResourceType::StageOutput,
+    ResourceType::SubpassInput,
+    ResourceType::StorageImage,
+    ResourceType::SampledImage,
+    ResourceType::AtomicCounter,
+    ResourceType::AccelerationStructure,
+    ResourceType::GlPlainUniform,
+    ResourceType::PushConstant,
+    ResourceType::ShaderRecordBuffer,
+    ResourceType::SeparateImage,
+    ResourceType::SeparateSamplers,
+];
+
 /// A handle to shader resources.
-pub struct ShaderResources(NonNull<spvc_resources_s>, PhantomCompiler);
+pub struct ShaderResources(pub(crate) NonNull<spvc_resources_s>, PhantomCompiler);
 
 impl<T> Compiler<T> {
     /// Query shader resources, use ids with reflection interface to modify or query binding points, etc.
+    ///
+    /// The shader resources are cached until the compiler is mutated in a way that may change
+    /// them, such as changing decorations, names, the entry point, or the enabled interface variables,
+    /// so repeated calls are cheap.
     pub fn shader_resources(&self) -> crate::error::Result<ShaderResources> {
         // SAFETY: 'ctx is Ok
         // since this gets allocated forever
         // https://github.com/KhronosGroup/SPIRV-Cross/blob/6a1fb66eef1bdca14acf7d0a51a3f883499d79f0/spirv_cross_c.cpp#L1925
         unsafe {
+            if let Some(resources) = self.shader_resources.get() {
+                return Ok(ShaderResources(resources, self.phantom()));
+            }
+
             let mut resources = std::ptr::null_mut();
             sys::spvc_compiler_create_shader_resources(self.ptr.as_ptr(), &mut resources)
                 .ok(self)?;
@@ -35,10 +77,18 @@ impl<T> Compiler<T> {
                 return Err(SpirvCrossError::OutOfMemory(String::from("Out of memory")));
             };
 
+            self.shader_resources.set(Some(resources));
             Ok(ShaderResources(resources, self.phantom()))
         }
     }
 
+    /// Reset the shader resources cached by [`Compiler::shader_resources`].
+    ///
+    /// This must be called by any mutation that may change the reflected shader resources.
+    pub(crate) fn invalidate_shader_resources(&mut self) {
+        *self.shader_resources.get_mut() = None;
+    }
+
     /// Query shader resources, but only return the variables which are part of active_variables.
     /// E.g.: get_shader_resources(get_active_variables()) to only return the variables which are statically
     /// accessed.
@@ -95,6 +145,22 @@ impl InterfaceVariableSet {
             handles
         }
     }
+
+    /// Check whether the variable is in the set of active interface variables.
+    pub fn contains(&self, variable: impl Into<Handle<VariableId>>) -> bool {
+        self.to_handles().contains(&variable.into())
+    }
+}
+
+/// An active interface variable, returned by [`Compiler::active_interface_variables_detailed`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct InterfaceVariable {
+    /// The interface variable.
+    pub id: Handle<VariableId>,
+    /// The storage class of the variable.
+    pub storage_class: spirv::StorageClass,
+    /// The `Location` decoration of the variable, if any.
+    pub location: Option<u32>,
 }
 
 // reflection
@@ -128,6 +194,29 @@ impl<T> Compiler<T> {
         }
     }
 
+    /// Get the active interface variables of the current entry point, along with their
+    /// storage class and `Location` decoration.
+    ///
+    /// The variables are sorted by ID, as with [`InterfaceVariableSet::to_handles`].
+    pub fn active_interface_variables_detailed(&self) -> error::Result<Vec<InterfaceVariable>> {
+        self.active_interface_variables()?
+            .to_handles()
+            .into_iter()
+            .map(|id| {
+                let storage_class = self.variable_storage_class(id)?;
+                let location = self
+                    .decoration(id, spirv::Decoration::Location)?
+                    .and_then(|location| location.as_literal());
+
+                Ok(InterfaceVariable {
+                    id,
+                    storage_class,
+                    location,
+                })
+            })
+            .collect()
+    }
+
     /// Sets the interface variables which are used during compilation.
     /// By default, all variables are used.
     /// Once set, [`Compiler::compile`] will only consider the set in active_variables.
@@ -140,12 +229,468 @@ impl<T> Compiler<T> {
                 "The interface variable set is invalid for this compiler instance.",
             )));
         }
+
+        self.invalidate_shader_resources();
         unsafe {
             sys::spvc_compiler_set_enabled_interface_variables(self.ptr.as_ptr(), set.0)
                 .ok(&*self)?;
+
+            for variable in &self.pinned_variables {
+                sys::spvc_rs_compiler_pin_interface_variable(self.ptr.as_ptr(), *variable)
+                    .ok(&*self)?;
+            }
+
             Ok(())
         }
     }
+
+    /// Pin a variable so that its declaration and decorations are preserved in the
+    /// compiled output, even if it is not statically used by the current entry point.
+    ///
+    /// Pinned variables are always added to the set of interface variables
+    /// passed to [`Compiler::set_enabled_interface_variables`].
+    pub fn pin_resource(&mut self, variable: impl Into<Handle<VariableId>>) -> error::Result<()> {
+        let variable = self.yield_id(variable.into())?;
+
+        self.invalidate_shader_resources();
+        unsafe {
+            sys::spvc_rs_compiler_pin_interface_variable(self.ptr.as_ptr(), variable).ok(&*self)?;
+        }
+
+        if !self.pinned_variables.contains(&variable) {
+            self.pinned_variables.push(variable);
+        }
+
+        Ok(())
+    }
+
+    /// Get the number of descriptors the resource binds, i.e. the `descriptorCount`
+    /// of a Vulkan descriptor set layout binding.
+    ///
+    /// Resources that are not arrays have a count of 1. Multidimensional arrays are flattened,
+    /// and their count is the product of all dimensions. If a dimension of a multidimensional array
+    /// is a specialization constant, the current value of the constant is used.
+    pub fn descriptor_count(
+        &self,
+        resource: impl Into<Handle<VariableId>>,
+    ) -> error::Result<DescriptorCount> {
+        let ty = self.variable_type(resource)?;
+        let TypeInner::Array { dimensions, .. } = self.type_description(ty)?.inner else {
+            return Ok(DescriptorCount::Fixed(1));
+        };
+
+        if dimensions.contains(&ArrayDimension::Literal(0)) {
+            return Ok(DescriptorCount::Runtime);
+        }
+
+        if let [ArrayDimension::Constant(constant)] = dimensions.as_slice() {
+            return Ok(DescriptorCount::SpecConstant(*constant));
+        }
+
+        let mut count = 1u32;
+        for dim in dimensions {
+            match dim {
+                ArrayDimension::Literal(len) => count = count.saturating_mul(len),
+                ArrayDimension::Constant(constant) => {
+                    let len = self.specialization_constant_value::<u32>(constant)?;
+                    count = count.saturating_mul(len);
+                }
+            }
+        }
+
+        Ok(DescriptorCount::Fixed(count))
+    }
+
+    /// Find pairs of descriptor resources that are bound to the same descriptor set and binding.
+    ///
+    /// Resources of the same [`ResourceType`] that share a binding are assumed to be
+    /// intentionally aliased, i.e. multiple views of the same storage buffer, and are not reported.
+    /// Resources without a `Binding` decoration are ignored, and resources without a
+    /// `DescriptorSet` decoration are considered to be in descriptor set 0.
+    pub fn find_binding_conflicts(&self) -> error::Result<Vec<BindingConflict>> {
+        let bound = self.bound_descriptor_resources()?;
+
+        let mut conflicts = Vec::new();
+        for (index, first) in bound.iter().enumerate() {
+            for second in &bound[index + 1..] {
+                if first.set == second.set
+                    && first.binding == second.binding
+                    && first.kind != second.kind
+                {
+                    conflicts.push(BindingConflict {
+                        set: first.set,
+                        binding: first.binding,
+                        first: first.id,
+                        first_type: first.kind,
+                        second: second.id,
+                        second_type: second.kind,
+                    });
+                }
+            }
+        }
+
+        Ok(conflicts)
+    }
+
+    /// Find pairs of descriptor resources that alias each other by sharing the same
+    /// descriptor set and binding.
+    ///
+    /// Pairs of resources with different [`ResourceType`]s are also reported by
+    /// [`Compiler::find_binding_conflicts`]. The `Aliased` decoration only declares that a variable
+    /// may alias some other memory, not which variable it aliases, so variables decorated
+    /// `Aliased` with distinct bindings are not reported. Likewise, resources decorated
+    /// `Restrict` that share a binding are still reported.
+    ///
+    /// Resources without a `Binding` decoration are ignored, and resources without a
+    /// `DescriptorSet` decoration are considered to be in descriptor set 0.
+    pub fn aliased_resources(
+        &self,
+    ) -> error::Result<Vec<(Handle<VariableId>, Handle<VariableId>)>> {
+        let bound = self.bound_descriptor_resources()?;
+
+        let mut aliased = Vec::new();
+        for (index, first) in bound.iter().enumerate() {
+            for second in &bound[index + 1..] {
+                if first.set == second.set && first.binding == second.binding {
+                    aliased.push((first.id, second.id));
+                }
+            }
+        }
+
+        Ok(aliased)
+    }
+
+    /// Get the descriptor set, binding, and type of every descriptor resource
+    /// decorated with a binding.
+    fn bound_descriptor_resources(&self) -> error::Result<Vec<BoundResource>> {
+        let resources = self.shader_resources()?;
+        let mut bound = Vec::new();
+        for ty in DESCRIPTOR_TYPES {
+            for resource in resources.resources_for_type(ty)? {
+                let Some(binding) = self
+                    .decoration(resource.id, spirv::Decoration::Binding)?
+                    .and_then(|binding| binding.as_literal())
+                else {
+                    continue;
+                };
+
+                let set = self
+                    .decoration(resource.id, spirv::Decoration::DescriptorSet)?
+                    .and_then(|set| set.as_literal())
+                    .unwrap_or(0);
+
+                bound.push(BoundResource {
+                    set,
+                    binding,
+                    id: resource.id,
+                    kind: ty,
+                });
+            }
+        }
+
+        Ok(bound)
+    }
+
+    /// Get the descriptor set, binding, resource type and descriptor count of every descriptor
+    /// resource decorated with a binding, sorted by descriptor set and binding.
+    ///
+    /// Resources without a `DescriptorSet` decoration are considered to be in descriptor set 0.
+    /// Resources that alias the same binding are each reported.
+    ///
+    /// This is a flattened, API-neutral view of the bindings of a descriptor set layout.
+    /// With the `vulkan` feature, `reflect::vulkan::descriptor_set_layouts` groups bindings
+    /// by descriptor set and maps them to Vulkan descriptor types.
+    pub fn descriptor_set_layout_bindings(&self) -> error::Result<Vec<DescriptorSetBinding>> {
+        let stage = self.execution_model()?;
+        let mut bindings = Vec::new();
+        for resource in self.bound_descriptor_resources()? {
+            bindings.push(DescriptorSetBinding {
+                set: resource.set,
+                binding: resource.binding,
+                id: resource.id,
+                kind: resource.kind,
+                count: self.descriptor_count(resource.id)?,
+                stage,
+            });
+        }
+
+        bindings.sort_by_key(|binding| (binding.set, binding.binding));
+        Ok(bindings)
+    }
+
+    /// Remap the `DescriptorSet` decoration of every descriptor resource with the given function.
+    ///
+    /// Resources without a `DescriptorSet` decoration are considered to be in descriptor set 0,
+    /// and are only decorated if `remap` moves them to another set.
+    /// Returns the number of resources whose descriptor set was changed.
+    pub fn remap_descriptor_sets(&mut self, remap: impl Fn(u32) -> u32) -> error::Result<usize> {
+        let resources = self.shader_resources()?;
+        let mut variables = Vec::new();
+        for ty in DESCRIPTOR_TYPES {
+            for resource in resources.resources_for_type(ty)? {
+                variables.push(resource.id);
+            }
+        }
+
+        let mut changed = 0;
+        for variable in variables {
+            let set = self
+                .decoration(variable, spirv::Decoration::DescriptorSet)?
+                .and_then(|set| set.as_literal())
+                .unwrap_or(0);
+
+            let remapped = remap(set);
+            if remapped != set {
+                self.set_decoration(variable, spirv::Decoration::DescriptorSet, Some(remapped))?;
+                changed += 1;
+            }
+        }
+
+        Ok(changed)
+    }
+
+    /// Recover the HLSL `register` binding of a descriptor resource compiled from HLSL,
+    /// i.e. `register(t0)` or `register(u1, space2)`.
+    ///
+    /// DXC does not record the register itself in the module, but maps the register number
+    /// to the `Binding` decoration and the register space to the `DescriptorSet` decoration.
+    /// The register class is read from the `UserTypeGOOGLE` decoration emitted by DXC with
+    /// `-fspv-reflect` if present, otherwise it is inferred from the type of the resource.
+    /// Bindings shifted with the `-fvk-*-shift` options can not be recovered.
+    ///
+    /// Returns `None` if the variable is not a descriptor resource with a `Binding` decoration.
+    pub fn hlsl_original_register(
+        &self,
+        variable: impl Into<Handle<VariableId>>,
+    ) -> error::Result<Option<HlslRegister>> {
+        let variable = variable.into();
+        let Some(binding) = self
+            .decoration(variable, spirv::Decoration::Binding)?
+            .and_then(|binding| binding.as_literal())
+        else {
+            return Ok(None);
+        };
+
+        let space = self
+            .decoration(variable, spirv::Decoration::DescriptorSet)?
+            .and_then(|set| set.as_literal())
+            .unwrap_or(0);
+
+        let user_type = match self.decoration(variable, spirv::Decoration::UserTypeGOOGLE)? {
+            Some(DecorationValue::String(user_type)) => Some(user_type.to_ascii_lowercase()),
+            _ => None,
+        };
+
+        let class = if let Some(user_type) = user_type {
+            let user_type = user_type.split(':').next().unwrap_or_default();
+            if matches!(user_type, "cbuffer" | "constantbuffer") {
+                HlslRegisterClass::ConstantBuffer
+            } else if ["rw", "append", "consume", "rasterizerordered"]
+                .iter()
+                .any(|prefix| user_type.starts_with(prefix))
+            {
+                HlslRegisterClass::UnorderedAccess
+            } else {
+                HlslRegisterClass::ShaderResource
+            }
+        } else {
+            let resources = self.shader_resources()?;
+            let mut resource_type = None;
+            for ty in DESCRIPTOR_TYPES {
+                if resources
+                    .resources_for_type(ty)?
+                    .any(|resource| resource.id == variable)
+                {
+                    resource_type = Some(ty);
+                    break;
+                }
+            }
+
+            match resource_type {
+                Some(ResourceType::UniformBuffer) => HlslRegisterClass::ConstantBuffer,
+                Some(ResourceType::StorageBuffer) => {
+                    // DXC decorates every member of read-only buffers as NonWritable.
+                    let read_only = matches!(
+                        self.buffer_block_decorations(variable)?,
+                        Some(decorations) if decorations.contains(&spirv::Decoration::NonWritable)
+                    );
+
+                    if read_only {
+                        HlslRegisterClass::ShaderResource
+                    } else {
+                        HlslRegisterClass::UnorderedAccess
+                    }
+                }
+                Some(ResourceType::StorageImage | ResourceType::AtomicCounter) => {
+                    HlslRegisterClass::UnorderedAccess
+                }
+                Some(ResourceType::SeparateSamplers) => HlslRegisterClass::Sampler,
+                Some(_) => HlslRegisterClass::ShaderResource,
+                None => return Ok(None),
+            }
+        };
+
+        Ok(Some(HlslRegister {
+            class,
+            register: binding,
+            space,
+        }))
+    }
+
+    /// Get the declared array length of the `ClipDistance` built-in, or 0 if it is unused.
+    ///
+    /// Both built-in outputs and inputs are considered, so this also reflects the
+    /// clip distances read by a fragment shader.
+    pub fn clip_distance_count(&self) -> error::Result<u32> {
+        self.builtin_array_length(spirv::BuiltIn::ClipDistance)
+    }
+
+    /// Get the declared array length of the `CullDistance` built-in, or 0 if it is unused.
+    ///
+    /// Both built-in outputs and inputs are considered, so this also reflects the
+    /// cull distances read by a fragment shader.
+    pub fn cull_distance_count(&self) -> error::Result<u32> {
+        self.builtin_array_length(spirv::BuiltIn::CullDistance)
+    }
+
+    /// Get the largest array length of the built-in among the stage inputs and outputs.
+    fn builtin_array_length(&self, builtin: spirv::BuiltIn) -> error::Result<u32> {
+        let resources = self.shader_resources()?;
+        let mut length = 0;
+
+        for ty in [
+            BuiltinResourceType::StageOutput,
+            BuiltinResourceType::StageInput,
+        ] {
+            for resource in resources.builtin_resources_for_type(ty)? {
+                if resource.builtin != builtin {
+                    continue;
+                }
+
+                let TypeInner::Array { dimensions, .. } =
+                    self.type_description(resource.value_type_id)?.inner
+                else {
+                    continue;
+                };
+
+                // The innermost dimension is the length of the built-in array itself,
+                // outer dimensions are per-vertex arrays of arrayed stage inputs and outputs.
+                let declared = match dimensions.first() {
+                    Some(ArrayDimension::Literal(len)) => *len,
+                    Some(ArrayDimension::Constant(constant)) => {
+                        self.specialization_constant_value::<u32>(*constant)?
+                    }
+                    None => continue,
+                };
+
+                length = length.max(declared);
+            }
+        }
+
+        Ok(length)
+    }
+}
+
+/// Two resources of different types bound to the same descriptor set and binding,
+/// returned by [`Compiler::find_binding_conflicts`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct BindingConflict {
+    /// The descriptor set of the conflicting resources.
+    pub set: u32,
+    /// The binding of the conflicting resources.
+    pub binding: u32,
+    /// The first resource bound to the set and binding.
+    pub first: Handle<VariableId>,
+    /// The type of the first resource.
+    pub first_type: ResourceType,
+    /// The second resource bound to the set and binding.
+    pub second: Handle<VariableId>,
+    /// The type of the second resource.
+    pub second_type: ResourceType,
+}
+
+/// A descriptor resource bound to a descriptor set and binding,
+/// returned by [`Compiler::descriptor_set_layout_bindings`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DescriptorSetBinding {
+    /// The descriptor set of the resource.
+    pub set: u32,
+    /// The binding of the resource.
+    pub binding: u32,
+    /// The resource bound to the set and binding.
+    pub id: Handle<VariableId>,
+    /// The type of the resource.
+    pub kind: ResourceType,
+    /// The number of descriptors bound by the resource.
+    pub count: DescriptorCount,
+    /// The execution model of the entry point the resource was reflected for.
+    pub stage: spirv::ExecutionModel,
+}
+
+/// A descriptor resource decorated with a binding, as found by
+/// `Compiler::bound_descriptor_resources`.
+#[derive(Debug, Copy, Clone)]
+struct BoundResource {
+    set: u32,
+    binding: u32,
+    id: Handle<VariableId>,
+    kind: ResourceType,
+}
+
+/// The class of an HLSL register, i.e. the letter of `register(t0)`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum HlslRegisterClass {
+    /// A constant buffer register, `b`.
+    ConstantBuffer,
+    /// A shader resource view register, `t`.
+    ShaderResource,
+    /// An unordered access view register, `u`.
+    UnorderedAccess,
+    /// A sampler register, `s`.
+    Sampler,
+}
+
+/// An HLSL `register` binding, returned by [`Compiler::hlsl_original_register`].
+///
+/// Formats as HLSL, i.e. `register(t0)` or `register(u1, space2)`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct HlslRegister {
+    /// The class of the register.
+    pub class: HlslRegisterClass,
+    /// The register number, from the `Binding` decoration.
+    pub register: u32,
+    /// The register space, from the `DescriptorSet` decoration.
+    pub space: u32,
+}
+
+impl Display for HlslRegister {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let class = match self.class {
+            HlslRegisterClass::ConstantBuffer => 'b',
+            HlslRegisterClass::ShaderResource => 't',
+            HlslRegisterClass::UnorderedAccess => 'u',
+            HlslRegisterClass::Sampler => 's',
+        };
+
+        if self.space == 0 {
+            write!(f, "register({class}{})", self.register)
+        } else {
+            write!(f, "register({class}{}, space{})", self.register, self.space)
+        }
+    }
+}
+
+/// The number of descriptors bound by a resource.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DescriptorCount {
+    /// A fixed number of descriptors.
+    Fixed(u32),
+    /// A runtime-sized array of descriptors.
+    ///
+    /// The number of descriptors must be provided when the descriptor set is allocated.
+    Runtime,
+    /// An array of descriptors sized by a specialization constant.
+    SpecConstant(Handle<ConstantId>),
 }
 
 /// Iterator over reflected resources, created by [`ShaderResources::resources_for_type`].
@@ -155,6 +700,38 @@ impl_iterator!(ResourceIter<'a>: Resource<'a> as map |s, o: &'a spvc_reflected_r
     Resource::from_raw(s.0.clone(), o)
 } for <'a> [1]);
 
+impl<'a> ResourceIter<'a> {
+    /// Collect the remaining resources, sorted by descriptor set, binding, location,
+    /// dual-source blending index, then name.
+    ///
+    /// Resources that are not decorated with a descriptor set, binding, location, or index are
+    /// considered to have a value of 0 for the missing decoration. Resources that compare
+    /// equal keep their original order.
+    pub fn sorted(self) -> Vec<Resource<'a>> {
+        let compiler = self.0.clone();
+        let decoration = |resource: &Resource, decoration: spirv::Decoration| unsafe {
+            // SAFETY: the resource handle was created by this compiler.
+            sys::spvc_compiler_get_decoration(
+                compiler.ptr.as_ptr(),
+                SpvId(resource.id.id()),
+                SpvDecoration(decoration as u32 as i32),
+            )
+        };
+
+        let mut resources: Vec<_> = self.collect();
+        resources.sort_by_cached_key(|resource| {
+            (
+                decoration(resource, spirv::Decoration::DescriptorSet),
+                decoration(resource, spirv::Decoration::Binding),
+                decoration(resource, spirv::Decoration::Location),
+                decoration(resource, spirv::Decoration::Index),
+                resource.name.to_string(),
+            )
+        });
+        resources
+    }
+}
+
 /// Iterator over reflected builtin resources, created by [`ShaderResources::builtin_resources_for_type`].
 pub struct BuiltinResourceIter<'a>(
     PhantomCompiler,
@@ -166,7 +743,11 @@ impl_iterator!(BuiltinResourceIter<'a>: BuiltinResource<'a> as and_then |s, o: &
 } for <'a> [1]);
 
 /// Description of a shader resource.
+///
+/// With the `serde` feature, handles are serialized as their `u32` ID part, and
+/// deserialized handles are detached from any compiler instance.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Resource<'a> {
     /// A handle to the variable this resource points to.
     pub id: Handle<VariableId>,
@@ -191,6 +772,15 @@ impl<'a> Resource<'a> {
             name: unsafe { CompilerStr::from_ptr(value.name, comp.ctx.clone()) },
         }
     }
+
+    /// Check whether the resource is statically accessed by the current entry point.
+    ///
+    /// This computes the set of active interface variables on every call. When checking
+    /// many resources, prefer calling [`Compiler::active_interface_variables`] once
+    /// and using [`InterfaceVariableSet::contains`].
+    pub fn is_active<T>(&self, compiler: &Compiler<T>) -> error::Result<bool> {
+        Ok(compiler.active_interface_variables()?.contains(self))
+    }
 }
 
 impl<'a, 'b> From<&'a Resource<'b>> for Handle<VariableId> {
@@ -218,7 +808,7 @@ impl ToStatic for Resource<'_> {
             id: self.id,
             base_type_id: self.base_type_id,
             type_id: self.type_id,
-            name: CompilerStr::from_string(self.name.to_string()),
+            name: self.name.to_static(),
         }
     }
 }
@@ -433,6 +1023,10 @@ impl Clone for AllResources<'_> {
 
 impl ShaderResources {
     /// Get an iterator for all resources of the given type.
+    ///
+    /// Resources are returned in the order of their SPIR-V IDs, which is stable for the same module,
+    /// but may change when the module is recompiled. Use [`ResourceIter::sorted`] to order resources
+    /// by their descriptor set and binding instead.
     pub fn resources_for_type(&self, ty: ResourceType) -> error::Result<ResourceIter<'static>> {
         // SAFETY: 'ctx is sound here,
         // https://github.com/KhronosGroup/SPIRV-Cross/blob/6a1fb66eef1bdca14acf7d0a51a3f883499d79f0/spirv_cross_c.cpp#L1802
@@ -482,6 +1076,40 @@ impl ShaderResources {
         Ok(BuiltinResourceIter(self.1.clone(), slice.iter()))
     }
 
+    /// Get the resource types that have at least one resource declared in the shader,
+    /// in the same order as the fields of [`AllResources`].
+    ///
+    /// Built-in resources are not included.
+    pub fn present_types(&self) -> error::Result<Vec<ResourceType>> {
+        let mut present = Vec::new();
+        for ty in RESOURCE_TYPES {
+            if self.resources_for_type(ty)?.next().is_some() {
+                present.push(ty);
+            }
+        }
+
+        Ok(present)
+    }
+
+    /// Get an iterator over all resources declared in the shader, along with their resource type.
+    ///
+    /// Resources are grouped by type, in the same order as the fields of [`AllResources`].
+    /// Unlike [`ShaderResources::all_resources`], this does not allocate a `Vec` for every resource type.
+    ///
+    /// Built-in resources are not included.
+    pub fn iter_all(
+        &self,
+    ) -> error::Result<impl Iterator<Item = (ResourceType, Resource<'static>)>> {
+        let mut iters = Vec::with_capacity(RESOURCE_TYPES.len());
+        for ty in RESOURCE_TYPES {
+            iters.push((ty, self.resources_for_type(ty)?));
+        }
+
+        Ok(iters
+            .into_iter()
+            .flat_map(|(ty, iter)| iter.map(move |resource| (ty, resource))))
+    }
+
     /// Get all resources declared in the shader.
     ///
     /// This will allocate a `Vec` for every resource type.
@@ -508,3 +1136,97 @@ impl ShaderResources {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::error::SpirvCrossError;
+    use crate::reflect::{HlslRegister, HlslRegisterClass};
+    use crate::Compiler;
+    use crate::{targets, Module};
+
+    #[test]
+    pub fn hlsl_original_register() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpExtension "SPV_GOOGLE_user_type"
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Fragment %main "main"
+            OpExecutionMode %main OriginUpperLeft
+            OpDecorate %block Block
+            OpMemberDecorate %block 0 Offset 0
+            OpDecorate %cbuffer DescriptorSet 0
+            OpDecorate %cbuffer Binding 0
+            OpDecorate %texture DescriptorSet 2
+            OpDecorate %texture Binding 1
+            OpDecorate %rw_texture DescriptorSet 0
+            OpDecorate %rw_texture Binding 3
+            OpDecorateString %rw_texture UserTypeGOOGLE "rwtexture2d:<float4>"
+            OpDecorate %sampler DescriptorSet 1
+            OpDecorate %sampler Binding 4
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %float = OpTypeFloat 32
+            %block = OpTypeStruct %float
+            %_ptr_Uniform_block = OpTypePointer Uniform %block
+            %cbuffer = OpVariable %_ptr_Uniform_block Uniform
+            %image = OpTypeImage %float 2D 0 0 0 1 Unknown
+            %_ptr_UniformConstant_image = OpTypePointer UniformConstant %image
+            %texture = OpVariable %_ptr_UniformConstant_image UniformConstant
+            %rw_image = OpTypeImage %float 2D 0 0 0 2 Rgba32f
+            %_ptr_UniformConstant_rw_image = OpTypePointer UniformConstant %rw_image
+            %rw_texture = OpVariable %_ptr_UniformConstant_rw_image UniformConstant
+            %sampler_type = OpTypeSampler
+            %_ptr_UniformConstant_sampler = OpTypePointer UniformConstant %sampler_type
+            %sampler = OpVariable %_ptr_UniformConstant_sampler UniformConstant
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+
+        let cbuffer = compiler
+            .hlsl_original_register(&resources.uniform_buffers[0])?
+            .expect("cbuffer has a register");
+        assert_eq!(
+            HlslRegister {
+                class: HlslRegisterClass::ConstantBuffer,
+                register: 0,
+                space: 0,
+            },
+            cbuffer
+        );
+        assert_eq!("register(b0)", cbuffer.to_string());
+
+        let texture = compiler
+            .hlsl_original_register(&resources.separate_images[0])?
+            .expect("texture has a register");
+        assert_eq!(
+            HlslRegister {
+                class: HlslRegisterClass::ShaderResource,
+                register: 1,
+                space: 2,
+            },
+            texture
+        );
+        assert_eq!("register(t1, space2)", texture.to_string());
+
+        // The register class is read from the UserTypeGOOGLE decoration.
+        let rw_texture = compiler
+            .hlsl_original_register(&resources.storage_images[0])?
+            .expect("rw texture has a register");
+        assert_eq!(HlslRegisterClass::UnorderedAccess, rw_texture.class);
+        assert_eq!("register(u3)", rw_texture.to_string());
+
+        let sampler = compiler
+            .hlsl_original_register(&resources.separate_samplers[0])?
+            .expect("sampler has a register");
+        assert_eq!("register(s4, space1)", sampler.to_string());
+
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use crate::handle::Handle;
 use crate::handle::{ConstantId, TypeId};
 use crate::sealed::Sealed;
 use crate::string::CompilerStr;
+use crate::ToStatic;
 use spirv_cross_sys as sys;
 
 /// The kind of scalar
@@ -177,6 +178,91 @@ pub struct StructType<'a> {
     pub members: Vec<StructMember<'a>>,
 }
 
+impl Sealed for Type<'_> {}
+impl ToStatic for Type<'_> {
+    type Static<'a>
+    = Type<'static>
+    where
+        'a: 'static;
+
+    fn to_static(&self) -> Self::Static<'static> {
+        Type {
+            id: self.id,
+            name: self.name.as_ref().map(ToStatic::to_static),
+            inner: self.inner.to_static(),
+            size_hint: self.size_hint.clone(),
+        }
+    }
+}
+
+impl Sealed for StructMember<'_> {}
+impl ToStatic for StructMember<'_> {
+    type Static<'a>
+    = StructMember<'static>
+    where
+        'a: 'static;
+
+    fn to_static(&self) -> Self::Static<'static> {
+        StructMember {
+            id: self.id,
+            struct_type: self.struct_type,
+            name: self.name.as_ref().map(ToStatic::to_static),
+            index: self.index,
+            offset: self.offset,
+            size: self.size,
+            matrix_stride: self.matrix_stride,
+            array_stride: self.array_stride,
+        }
+    }
+}
+
+impl Sealed for StructType<'_> {}
+impl ToStatic for StructType<'_> {
+    type Static<'a>
+    = StructType<'static>
+    where
+        'a: 'static;
+
+    fn to_static(&self) -> Self::Static<'static> {
+        StructType {
+            id: self.id,
+            size: self.size,
+            members: self.members.iter().map(ToStatic::to_static).collect(),
+        }
+    }
+}
+
+/// The trailing runtime array of a struct, returned by [`Compiler::runtime_array_element`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RuntimeArrayElement {
+    /// The element type of the runtime array.
+    ///
+    /// For runtime arrays of arrays, this is the innermost element type.
+    pub element_type: Handle<TypeId>,
+    /// The stride, in bytes, between elements of the runtime array.
+    pub stride: u32,
+}
+
+/// Layout information for a matrix type.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MatrixType {
+    /// The number of columns of the matrix.
+    pub columns: u32,
+    /// The number of rows of the matrix, i.e. the vector size of a column.
+    pub rows: u32,
+    /// The bit width of the scalar type of the matrix.
+    pub scalar_width: BitWidth,
+    /// The matrix stride, if any.
+    ///
+    /// Matrix strides are only decorated on struct members, so this is
+    /// always `None` for a bare matrix type.
+    pub stride: Option<u32>,
+    /// Whether the matrix is laid out in row-major order.
+    ///
+    /// Matrices are column major unless decorated otherwise.
+    pub row_major: bool,
+}
+
 /// Valid values that specify the dimensions of an array.
 ///
 /// Most of the time, these will be [`ArrayDimension::Literal`].
@@ -210,16 +296,27 @@ pub enum ImageClass {
         arrayed: bool,
     },
     /// Separate image.
+    #[non_exhaustive]
     Texture {
+        /// Whether this is a depth image.
+        ///
+        /// Separate images are usually not marked as depth images, and whether
+        /// a depth comparison is done is decided by the sampler instead.
+        depth: bool,
         /// Whether this is a multisampled image.
         multisampled: bool,
         /// Whether this image is arrayed.
         arrayed: bool,
     },
     /// Storage images.
+    #[non_exhaustive]
     Storage {
         /// The image format of the storage image.
         format: spirv::ImageFormat,
+        /// Whether this is a multisampled image.
+        multisampled: bool,
+        /// Whether this image is arrayed.
+        arrayed: bool,
     },
 }
 
@@ -316,6 +413,110 @@ pub enum TypeInner<'a> {
     Sampler,
 }
 
+impl Sealed for TypeInner<'_> {}
+impl ToStatic for TypeInner<'_> {
+    type Static<'a>
+    = TypeInner<'static>
+    where
+        'a: 'static;
+
+    fn to_static(&self) -> Self::Static<'static> {
+        match self {
+            TypeInner::Unknown => TypeInner::Unknown,
+            TypeInner::Void => TypeInner::Void,
+            TypeInner::Pointer {
+                base,
+                storage,
+                forward,
+            } => TypeInner::Pointer {
+                base: *base,
+                storage: *storage,
+                forward: *forward,
+            },
+            TypeInner::Struct(s) => TypeInner::Struct(s.to_static()),
+            TypeInner::Scalar(s) => TypeInner::Scalar(s.clone()),
+            TypeInner::Vector { width, scalar } => TypeInner::Vector {
+                width: *width,
+                scalar: scalar.clone(),
+            },
+            TypeInner::Matrix {
+                columns,
+                rows,
+                scalar,
+            } => TypeInner::Matrix {
+                columns: *columns,
+                rows: *rows,
+                scalar: scalar.clone(),
+            },
+            TypeInner::Array {
+                base,
+                storage,
+                dimensions,
+                stride,
+            } => TypeInner::Array {
+                base: *base,
+                storage: *storage,
+                dimensions: dimensions.clone(),
+                stride: *stride,
+            },
+            TypeInner::Image(i) => TypeInner::Image(i.clone()),
+            TypeInner::AccelerationStructure => TypeInner::AccelerationStructure,
+            TypeInner::Sampler => TypeInner::Sampler,
+        }
+    }
+}
+
+/// The top-level kind of a type, returned by [`Compiler::type_kind`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TypeKind {
+    /// Unknown type.
+    Unknown,
+    /// The void type.
+    Void,
+    /// A scalar boolean.
+    Bool,
+    /// A scalar signed or unsigned integer.
+    Int,
+    /// A scalar floating point number.
+    Float,
+    /// A vector type.
+    Vector,
+    /// A matrix type.
+    Matrix,
+    /// A texture or storage image.
+    Image,
+    /// An opaque sampler.
+    Sampler,
+    /// A combined image and sampler.
+    SampledImage,
+    /// An array type with a size known at compile time, or specified with a specialization constant.
+    Array,
+    /// An array type with a size only known at runtime.
+    RuntimeArray,
+    /// A struct type.
+    Struct,
+    /// A pointer to another type, including atomic counters.
+    Pointer,
+    /// An opaque acceleration structure.
+    AccelerationStructure,
+}
+
+/// A buffer layout rule used to compute the size of a type with [`Compiler::type_size`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BufferLayout {
+    /// The `std140` layout, used by default for uniform buffers.
+    ///
+    /// The alignment of arrays, matrices and structs is rounded up to 16 bytes.
+    Std140,
+    /// The `std430` layout, used by default for storage buffers.
+    Std430,
+    /// The scalar block layout from `GL_EXT_scalar_block_layout`,
+    /// commonly used for buffer references.
+    ///
+    /// Every type is aligned to the alignment of its scalar components.
+    Scalar,
+}
+
 /// A size hole requiring the stride of a matrix,
 /// and whether the matrix is column or row major.
 ///
@@ -659,7 +860,11 @@ impl<T> Compiler<T> {
             };
 
             let class = if storage {
-                ImageClass::Storage { format }
+                ImageClass::Storage {
+                    format,
+                    multisampled,
+                    arrayed,
+                }
             } else if base_ty == BaseType::SampledImage {
                 ImageClass::Sampled {
                     depth,
@@ -668,6 +873,7 @@ impl<T> Compiler<T> {
                 }
             } else {
                 ImageClass::Texture {
+                    depth,
                     multisampled,
                     arrayed,
                 }
@@ -682,6 +888,99 @@ impl<T> Compiler<T> {
         }
     }
 
+    /// Returns whether the type is a forward pointer, declared with `OpTypeForwardPointer`.
+    ///
+    /// Forward pointers are used to declare recursive types, such as a buffer reference
+    /// to a struct containing a buffer reference to itself. Type descriptions never
+    /// resolve the base type of a pointer, so a type walker can avoid cycles by not following
+    /// the base of a forward pointer, or by tracking the types already visited.
+    pub fn type_is_forward_pointer(&self, id: Handle<TypeId>) -> error::Result<bool> {
+        let id = self.yield_id(id)?;
+
+        unsafe {
+            // SAFETY: yield_id ensures this is valid for the ID
+            Ok(sys::spvc_rs_compiler_type_is_forward_pointer(
+                self.ptr.as_ptr(),
+                id,
+            ))
+        }
+    }
+
+    /// Get the top-level kind of the given type ID.
+    ///
+    /// This is cheaper than [`Compiler::type_description`], as it does not
+    /// build the full [`TypeInner`] for the type.
+    pub fn type_kind(&self, id: Handle<TypeId>) -> error::Result<TypeKind> {
+        let id = self.yield_id(id)?;
+
+        unsafe {
+            let ty = sys::spvc_compiler_get_type_handle(self.ptr.as_ptr(), id);
+
+            let array_dim_len = sys::spvc_type_get_num_array_dimensions(ty);
+            if array_dim_len != 0 {
+                // The outermost dimension is last, and runtime arrays have a literal size of 0.
+                let outer = array_dim_len - 1;
+                let is_runtime = sys::spvc_type_array_dimension_is_literal(ty, outer)
+                    && sys::spvc_type_get_array_dimension(ty, outer).0 == 0;
+
+                return Ok(if is_runtime {
+                    TypeKind::RuntimeArray
+                } else {
+                    TypeKind::Array
+                });
+            }
+
+            if sys::spvc_rs_type_is_pointer(ty) {
+                return Ok(TypeKind::Pointer);
+            }
+
+            let base_ty = sys::spvc_type_get_basetype(ty);
+            let vec_size = sys::spvc_type_get_vector_size(ty);
+            let columns = sys::spvc_type_get_columns(ty);
+
+            Ok(match base_ty {
+                BaseType::Boolean
+                | BaseType::Int8
+                | BaseType::Uint8
+                | BaseType::Int16
+                | BaseType::Uint16
+                | BaseType::Int32
+                | BaseType::Uint32
+                | BaseType::Int64
+                | BaseType::Uint64
+                | BaseType::Fp16
+                | BaseType::Fp32
+                | BaseType::Fp64
+                    if vec_size > 1 =>
+                {
+                    if columns > 1 {
+                        TypeKind::Matrix
+                    } else {
+                        TypeKind::Vector
+                    }
+                }
+                BaseType::Boolean => TypeKind::Bool,
+                BaseType::Int8
+                | BaseType::Uint8
+                | BaseType::Int16
+                | BaseType::Uint16
+                | BaseType::Int32
+                | BaseType::Uint32
+                | BaseType::Int64
+                | BaseType::Uint64 => TypeKind::Int,
+                BaseType::Fp16 | BaseType::Fp32 | BaseType::Fp64 => TypeKind::Float,
+                BaseType::Struct => TypeKind::Struct,
+                BaseType::Image => TypeKind::Image,
+                BaseType::SampledImage => TypeKind::SampledImage,
+                BaseType::Sampler => TypeKind::Sampler,
+                BaseType::AtomicCounter => TypeKind::Pointer,
+                BaseType::AccelerationStructure => TypeKind::AccelerationStructure,
+                BaseType::Void => TypeKind::Void,
+                BaseType::Unknown => TypeKind::Unknown,
+            })
+        }
+    }
+
     /// Get the type description for the given type ID.
     ///
     /// In most cases, a `base_type_id` should be passed in unless
@@ -901,7 +1200,10 @@ impl<T> Compiler<T> {
 
     /// Check if the struct has a runtime array. If so, return the stride
     /// of the array.
-    fn struct_has_runtime_array(&self, struct_type: &StructType) -> error::Result<Option<u32>> {
+    pub(crate) fn struct_has_runtime_array(
+        &self,
+        struct_type: &StructType,
+    ) -> error::Result<Option<u32>> {
         if let Some(last) = struct_type.members.last() {
             let Some(array_stride) = last.array_stride else {
                 return Ok(None);
@@ -918,6 +1220,286 @@ impl<T> Compiler<T> {
         Ok(None)
     }
 
+    /// Get the layout of a matrix type.
+    ///
+    /// Since matrix strides and majorness are decorated on struct members,
+    /// the returned stride is always `None` and the matrix is considered column major.
+    /// Use [`Compiler::struct_member_matrix_type`] to get the layout of a matrix inside a struct.
+    ///
+    /// If the type is not a matrix, returns [`SpirvCrossError::InvalidArgument`].
+    pub fn matrix_type(&self, ty: Handle<TypeId>) -> error::Result<MatrixType> {
+        let TypeInner::Matrix {
+            columns,
+            rows,
+            scalar,
+        } = self.type_description(ty)?.inner
+        else {
+            return Err(SpirvCrossError::InvalidArgument(String::from(
+                "The type is not a matrix",
+            )));
+        };
+
+        Ok(MatrixType {
+            columns,
+            rows,
+            scalar_width: scalar.size,
+            stride: None,
+            row_major: false,
+        })
+    }
+
+    /// Get the layout of a matrix struct member, including its stride and majorness.
+    ///
+    /// If the member is an array of matrices, the layout of the matrix element type is returned.
+    ///
+    /// If the member is not a matrix or an array of matrices, returns [`SpirvCrossError::InvalidArgument`].
+    pub fn struct_member_matrix_type(&self, member: &StructMember) -> error::Result<MatrixType> {
+        let mut ty = self.type_description(member.id)?;
+        while let TypeInner::Array { base, .. } = ty.inner {
+            ty = self.type_description(base)?;
+        }
+
+        let mut matrix = self.matrix_type(ty.id)?;
+        matrix.stride = member.matrix_stride;
+        matrix.row_major = self
+            .member_decoration(member, spirv::Decoration::RowMajor)?
+            .is_some();
+
+        Ok(matrix)
+    }
+
+    /// Get the offset in bytes of a nested struct member from the beginning of the root struct.
+    ///
+    /// The path is a list of member indices, starting from the members of `root_ty`.
+    /// For example, `[1, 0]` is the first member of the struct at the second member of `root_ty`.
+    ///
+    /// If a member along the path is an array of structs, the path continues into the first
+    /// element of the array.
+    ///
+    /// If the path does not refer to a struct member, returns [`SpirvCrossError::InvalidArgument`].
+    pub fn absolute_member_offset(
+        &self,
+        root_ty: Handle<TypeId>,
+        path: &[u32],
+    ) -> error::Result<u32> {
+        let mut ty = root_ty;
+        let mut offset = 0u32;
+
+        for &index in path {
+            let mut description = self.type_description(ty)?;
+            while let TypeInner::Array { base, .. } = description.inner {
+                description = self.type_description(base)?;
+            }
+
+            let TypeInner::Struct(struct_type) = description.inner else {
+                return Err(SpirvCrossError::InvalidArgument(String::from(
+                    "The member path does not refer to a struct",
+                )));
+            };
+
+            let Some(member) = struct_type.members.get(index as usize) else {
+                return Err(SpirvCrossError::InvalidArgument(format!(
+                    "The struct has no member at index {index}"
+                )));
+            };
+
+            offset += member.offset;
+            ty = member.id;
+        }
+
+        Ok(offset)
+    }
+
+    /// Get the element type and stride of the trailing runtime array of a struct,
+    /// as commonly found at the end of storage buffer blocks.
+    ///
+    /// The offset of element `i` of the runtime array is the offset of the last member of
+    /// the struct plus `i * stride`.
+    ///
+    /// Returns `None` if the struct does not end with a runtime array.
+    /// If the type is not a struct, returns [`SpirvCrossError::InvalidArgument`].
+    pub fn runtime_array_element(
+        &self,
+        ty: Handle<TypeId>,
+    ) -> error::Result<Option<RuntimeArrayElement>> {
+        let TypeInner::Struct(struct_type) = self.type_description(ty)?.inner else {
+            return Err(SpirvCrossError::InvalidArgument(String::from(
+                "The type is not a struct",
+            )));
+        };
+
+        let Some(stride) = self.struct_has_runtime_array(&struct_type)? else {
+            return Ok(None);
+        };
+
+        // struct_has_runtime_array guarantees that there is a last member.
+        let Some(last) = struct_type.members.last() else {
+            return Ok(None);
+        };
+
+        let TypeInner::Array { base, .. } = self.type_description(last.id)?.inner else {
+            return Ok(None);
+        };
+
+        Ok(Some(RuntimeArrayElement {
+            element_type: base,
+            stride,
+        }))
+    }
+
+    /// Get the type handle of a struct type, or [`SpirvCrossError::InvalidArgument`]
+    /// if the type is not a struct.
+    fn struct_type_handle(&self, ty: Handle<TypeId>) -> error::Result<sys::spvc_type> {
+        let id = self.yield_id(ty)?;
+        unsafe {
+            // SAFETY: yield_id ensures this is valid for the ID
+            let ty = sys::spvc_compiler_get_type_handle(self.ptr.as_ptr(), id);
+            if sys::spvc_type_get_basetype(ty) != BaseType::Struct {
+                return Err(SpirvCrossError::InvalidArgument(String::from(
+                    "The type is not a struct",
+                )));
+            }
+
+            Ok(ty)
+        }
+    }
+
+    /// Get the type handle of a struct type, or [`SpirvCrossError::InvalidArgument`]
+    /// if the type is not a struct or has no member at the given index.
+    fn struct_member_type_handle(
+        &self,
+        ty: Handle<TypeId>,
+        index: u32,
+    ) -> error::Result<sys::spvc_type> {
+        let ty = self.struct_type_handle(ty)?;
+        if index >= unsafe { sys::spvc_type_get_num_member_types(ty) } {
+            return Err(SpirvCrossError::InvalidArgument(format!(
+                "The struct has no member at index {index}"
+            )));
+        }
+
+        Ok(ty)
+    }
+
+    /// Get the declared size in bytes of a struct type, as laid out with its `Offset`
+    /// and stride decorations.
+    ///
+    /// If the struct ends with a runtime array, the runtime array is considered to have
+    /// zero elements. Use [`Compiler::declared_struct_size_runtime_array`] to include elements
+    /// of the runtime array.
+    ///
+    /// If the type is not a struct, returns [`SpirvCrossError::InvalidArgument`].
+    pub fn declared_struct_size(&self, ty: Handle<TypeId>) -> error::Result<usize> {
+        let ty = self.struct_type_handle(ty)?;
+        unsafe {
+            let mut size = 0;
+            sys::spvc_compiler_get_declared_struct_size(self.ptr.as_ptr(), ty, &mut size)
+                .ok(self)?;
+            Ok(size)
+        }
+    }
+
+    /// Get the declared size in bytes of a struct type, with its trailing runtime array
+    /// sized to `array_size` elements.
+    ///
+    /// If the type is not a struct, returns [`SpirvCrossError::InvalidArgument`].
+    pub fn declared_struct_size_runtime_array(
+        &self,
+        ty: Handle<TypeId>,
+        array_size: usize,
+    ) -> error::Result<usize> {
+        let ty = self.struct_type_handle(ty)?;
+        unsafe {
+            let mut size = 0;
+            sys::spvc_compiler_get_declared_struct_size_runtime_array(
+                self.ptr.as_ptr(),
+                ty,
+                array_size,
+                &mut size,
+            )
+            .ok(self)?;
+            Ok(size)
+        }
+    }
+
+    /// Get the declared size in bytes of the member of a struct type at the given index.
+    ///
+    /// If the type is not a struct, returns [`SpirvCrossError::InvalidArgument`].
+    pub fn declared_struct_member_size(
+        &self,
+        ty: Handle<TypeId>,
+        index: u32,
+    ) -> error::Result<usize> {
+        let ty = self.struct_member_type_handle(ty, index)?;
+        unsafe {
+            let mut size = 0;
+            sys::spvc_compiler_get_declared_struct_member_size(
+                self.ptr.as_ptr(),
+                ty,
+                index,
+                &mut size,
+            )
+            .ok(self)?;
+            Ok(size)
+        }
+    }
+
+    /// Get the `Offset` decoration of the member of a struct type at the given index.
+    ///
+    /// If the type is not a struct, or the member is not decorated with `Offset`,
+    /// returns an error.
+    pub fn struct_member_offset(&self, ty: Handle<TypeId>, index: u32) -> error::Result<u32> {
+        let ty = self.struct_member_type_handle(ty, index)?;
+        unsafe {
+            let mut offset = 0;
+            sys::spvc_compiler_type_struct_member_offset(self.ptr.as_ptr(), ty, index, &mut offset)
+                .ok(self)?;
+            Ok(offset)
+        }
+    }
+
+    /// Get the `ArrayStride` decoration of the array member of a struct type at the given index.
+    ///
+    /// If the type is not a struct, or the member is not decorated with `ArrayStride`,
+    /// returns an error.
+    pub fn struct_member_array_stride(&self, ty: Handle<TypeId>, index: u32) -> error::Result<u32> {
+        let ty = self.struct_member_type_handle(ty, index)?;
+        unsafe {
+            let mut stride = 0;
+            sys::spvc_compiler_type_struct_member_array_stride(
+                self.ptr.as_ptr(),
+                ty,
+                index,
+                &mut stride,
+            )
+            .ok(self)?;
+            Ok(stride)
+        }
+    }
+
+    /// Get the `MatrixStride` decoration of the matrix member of a struct type at the given index.
+    ///
+    /// If the type is not a struct, or the member is not decorated with `MatrixStride`,
+    /// returns an error.
+    pub fn struct_member_matrix_stride(
+        &self,
+        ty: Handle<TypeId>,
+        index: u32,
+    ) -> error::Result<u32> {
+        let ty = self.struct_member_type_handle(ty, index)?;
+        unsafe {
+            let mut stride = 0;
+            sys::spvc_compiler_type_struct_member_matrix_stride(
+                self.ptr.as_ptr(),
+                ty,
+                index,
+                &mut stride,
+            )
+            .ok(self)?;
+            Ok(stride)
+        }
+    }
+
     /// Get the underlying type of the variable.
     pub fn variable_type(
         &self,
@@ -934,13 +1516,214 @@ impl<T> Compiler<T> {
             Ok(self.create_handle(type_id))
         }
     }
+
+    /// Get the storage class of the variable.
+    pub fn variable_storage_class(
+        &self,
+        variable: impl Into<Handle<VariableId>>,
+    ) -> error::Result<StorageClass> {
+        let variable = variable.into();
+        let variable_id = self.yield_id(variable)?;
+
+        unsafe {
+            let mut type_id = TypeId(SpvId(0));
+            sys::spvc_rs_compiler_variable_get_type(self.ptr.as_ptr(), variable_id, &mut type_id)
+                .ok(self)?;
+
+            let ty = sys::spvc_compiler_get_type_handle(self.ptr.as_ptr(), type_id);
+            let storage_class = sys::spvc_type_get_storage_class(ty);
+
+            let Some(storage_class) = StorageClass::from_u32(storage_class.0 as u32) else {
+                return Err(SpirvCrossError::InvalidSpirv(format!(
+                    "Unknown StorageClass found: {}",
+                    storage_class.0
+                )));
+            };
+
+            Ok(storage_class)
+        }
+    }
+
+    /// Get the total declared size in bytes of all variables with the `Workgroup` storage class,
+    /// i.e. the shared memory used by a compute shader.
+    ///
+    /// The size of each variable is the [declared size](TypeSizeHint::declared) of its type.
+    /// Workgroup variables usually do not carry explicit layout decorations, so this should be
+    /// treated as an estimate of the memory required.
+    pub fn shared_memory_size(&self) -> error::Result<usize> {
+        let variables = unsafe {
+            let mut length = 0;
+            sys::spvc_rs_compiler_get_workgroup_variables(
+                self.ptr.as_ptr(),
+                std::ptr::null_mut(),
+                &mut length,
+            )
+            .ok(self)?;
+
+            let mut variables = vec![VariableId(SpvId(0)); length];
+            sys::spvc_rs_compiler_get_workgroup_variables(
+                self.ptr.as_ptr(),
+                variables.as_mut_ptr(),
+                &mut length,
+            )
+            .ok(self)?;
+
+            variables
+        };
+
+        let mut size = 0;
+        for variable in variables {
+            let variable = unsafe { self.create_handle(variable) };
+            let mut ty = self.type_description(self.variable_type(variable)?)?;
+            if let TypeInner::Pointer { base, .. } = ty.inner {
+                ty = self.type_description(base)?;
+            }
+
+            size += ty.size_hint.declared();
+        }
+
+        Ok(size)
+    }
+
+    /// Get the size in bytes of a type when laid out in a buffer according to the given layout rule.
+    ///
+    /// Unlike the [size hint](Type::size_hint), this does not consider any explicit
+    /// `Offset`, `ArrayStride` or `MatrixStride` decorations. The size of a struct
+    /// is padded up to its alignment, and runtime arrays are considered to have no elements.
+    /// Array sizes specified by specialization constants are resolved to their current value.
+    ///
+    /// Pointers are only allowed to buffer references in the `PhysicalStorageBuffer` storage class.
+    /// If the type can not be placed in a buffer, returns [`SpirvCrossError::InvalidArgument`].
+    pub fn type_size(&self, ty: Handle<TypeId>, layout: BufferLayout) -> error::Result<usize> {
+        Ok(self.type_layout(ty, layout, false)?.0)
+    }
+
+    /// Get the size and alignment of a type under a buffer layout rule.
+    fn type_layout(
+        &self,
+        ty: Handle<TypeId>,
+        layout: BufferLayout,
+        row_major: bool,
+    ) -> error::Result<(usize, usize)> {
+        fn round_up(value: usize, alignment: usize) -> usize {
+            (value + alignment - 1) / alignment * alignment
+        }
+
+        fn vector_alignment(width: u32, scalar: usize, layout: BufferLayout) -> usize {
+            match (layout, width) {
+                (BufferLayout::Scalar, _) | (_, 1) => scalar,
+                (_, 2) => 2 * scalar,
+                _ => 4 * scalar,
+            }
+        }
+
+        Ok(match self.type_description(ty)?.inner {
+            TypeInner::Scalar(scalar) => {
+                let size = scalar.size.byte_size();
+                (size, size)
+            }
+            TypeInner::Vector { width, scalar } => {
+                let scalar = scalar.size.byte_size();
+                (
+                    width as usize * scalar,
+                    vector_alignment(width, scalar, layout),
+                )
+            }
+            TypeInner::Matrix {
+                columns,
+                rows,
+                scalar,
+            } => {
+                // A matrix is laid out as an array of its columns, or its rows if row major.
+                let (count, width) = if row_major {
+                    (rows, columns)
+                } else {
+                    (columns, rows)
+                };
+
+                let scalar = scalar.size.byte_size();
+                let (stride, alignment) = match layout {
+                    BufferLayout::Std140 => {
+                        let stride = round_up(vector_alignment(width, scalar, layout), 16);
+                        (stride, stride)
+                    }
+                    BufferLayout::Std430 => {
+                        let stride = vector_alignment(width, scalar, layout);
+                        (stride, stride)
+                    }
+                    BufferLayout::Scalar => (width as usize * scalar, scalar),
+                };
+
+                (count as usize * stride, alignment)
+            }
+            TypeInner::Array {
+                base, dimensions, ..
+            } => {
+                let mut count = 1usize;
+                for dim in dimensions {
+                    match dim {
+                        ArrayDimension::Literal(a) => count *= a as usize,
+                        ArrayDimension::Constant(c) => {
+                            count *= self.specialization_constant_value::<u32>(c)? as usize
+                        }
+                    }
+                }
+
+                let (size, mut alignment) = self.type_layout(base, layout, row_major)?;
+                if layout == BufferLayout::Std140 {
+                    alignment = round_up(alignment, 16);
+                }
+
+                (count * round_up(size, alignment), alignment)
+            }
+            TypeInner::Struct(struct_type) => {
+                let mut size = 0;
+                let mut alignment = 1;
+                for member in &struct_type.members {
+                    let row_major = self
+                        .member_decoration(member, spirv::Decoration::RowMajor)?
+                        .is_some();
+                    let (member_size, member_alignment) =
+                        self.type_layout(member.id, layout, row_major)?;
+
+                    size = round_up(size, member_alignment) + member_size;
+                    alignment = alignment.max(member_alignment);
+                }
+
+                if layout == BufferLayout::Std140 {
+                    alignment = round_up(alignment, 16);
+                }
+
+                (round_up(size, alignment), alignment)
+            }
+            TypeInner::Pointer {
+                storage: StorageClass::PhysicalStorageBuffer,
+                ..
+            } => (
+                BitWidth::DoubleWord.byte_size(),
+                BitWidth::DoubleWord.byte_size(),
+            ),
+            TypeInner::Pointer { .. }
+            | TypeInner::Image(_)
+            | TypeInner::AccelerationStructure
+            | TypeInner::Sampler
+            | TypeInner::Unknown
+            | TypeInner::Void => {
+                return Err(SpirvCrossError::InvalidArgument(String::from(
+                    "The type can not be laid out in a buffer",
+                )))
+            }
+        })
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::error::SpirvCrossError;
+    use crate::reflect::{BufferLayout, ImageClass, TypeInner, TypeKind};
     use crate::Compiler;
     use crate::{targets, Module};
+    use spirv::StorageClass;
 
     static BASIC_SPV: &[u8] = include_bytes!("../../basic.spv");
 
@@ -1015,4 +1798,461 @@ mod test {
         eprintln!("{:?}", resources);
         Ok(())
     }
+
+    #[test]
+    pub fn type_kind_test() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+
+        let buffer = &resources.uniform_buffers[0];
+        assert_eq!(TypeKind::Struct, compiler.type_kind(buffer.base_type_id)?);
+        assert_eq!(TypeKind::Pointer, compiler.type_kind(buffer.type_id)?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn type_size_test() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+
+        let buffer = resources.uniform_buffers[0].base_type_id;
+        assert_eq!(64, compiler.type_size(buffer, BufferLayout::Std140)?);
+
+        let TypeInner::Struct(ubo) = compiler.type_description(buffer)?.inner else {
+            panic!("UBO is not a struct");
+        };
+
+        let mat4 = ubo.members[0].id;
+        assert_eq!(64, compiler.type_size(mat4, BufferLayout::Std430)?);
+        assert_eq!(64, compiler.type_size(mat4, BufferLayout::Scalar)?);
+
+        // struct Inner { float a; float b; };
+        // struct Outer { vec3 v[2]; float f; Inner inner; };
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint GLCompute %main "main" %ubo
+            OpExecutionMode %main LocalSize 1 1 1
+            OpDecorate %arr_v3float ArrayStride 16
+            OpMemberDecorate %inner 0 Offset 0
+            OpMemberDecorate %inner 1 Offset 4
+            OpDecorate %outer Block
+            OpMemberDecorate %outer 0 Offset 0
+            OpMemberDecorate %outer 1 Offset 32
+            OpMemberDecorate %outer 2 Offset 48
+            OpDecorate %ubo DescriptorSet 0
+            OpDecorate %ubo Binding 0
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %float = OpTypeFloat 32
+            %v3float = OpTypeVector %float 3
+            %uint = OpTypeInt 32 0
+            %uint_2 = OpConstant %uint 2
+            %arr_v3float = OpTypeArray %v3float %uint_2
+            %inner = OpTypeStruct %float %float
+            %outer = OpTypeStruct %arr_v3float %float %inner
+            %_ptr_Uniform_outer = OpTypePointer Uniform %outer
+            %ubo = OpVariable %_ptr_Uniform_outer Uniform
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        let outer = compiler
+            .shader_resources()?
+            .all_resources()?
+            .uniform_buffers[0]
+            .base_type_id;
+
+        // std140 rounds the vec3 array stride and the nested struct up to 16 bytes,
+        // std430 only the vec3 array stride, and scalar neither.
+        assert_eq!(64, compiler.type_size(outer, BufferLayout::Std140)?);
+        assert_eq!(48, compiler.type_size(outer, BufferLayout::Std430)?);
+        assert_eq!(36, compiler.type_size(outer, BufferLayout::Scalar)?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn declared_struct_size_test() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+
+        let buffer = resources.uniform_buffers[0].base_type_id;
+        assert_eq!(64, compiler.declared_struct_size(buffer)?);
+        assert_eq!(64, compiler.declared_struct_size_runtime_array(buffer, 4)?);
+        assert_eq!(64, compiler.declared_struct_member_size(buffer, 0)?);
+        assert!(compiler.declared_struct_member_size(buffer, 1).is_err());
+
+        let TypeInner::Struct(ubo) = compiler.type_description(buffer)?.inner else {
+            panic!("UBO is not a struct");
+        };
+        assert!(compiler.declared_struct_size(ubo.members[0].id).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn struct_member_layout_test() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+
+        let buffer = resources.uniform_buffers[0].base_type_id;
+        assert_eq!(0, compiler.struct_member_offset(buffer, 0)?);
+        assert_eq!(16, compiler.struct_member_matrix_stride(buffer, 0)?);
+        assert!(compiler.struct_member_array_stride(buffer, 0).is_err());
+        assert!(compiler.struct_member_offset(buffer, 1).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn image_type_test() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+
+        let texture = resources.sampled_images[0].base_type_id;
+        let TypeInner::Image(image) = compiler.type_description(texture)?.inner else {
+            panic!("sampled image is not an image");
+        };
+
+        assert_eq!(spirv::Dim::Dim2D, image.dimension);
+        assert_eq!(
+            ImageClass::Sampled {
+                depth: false,
+                multisampled: false,
+                arrayed: false,
+            },
+            image.class
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn runtime_array_element() -> Result<(), SpirvCrossError> {
+        // struct Header { uint count; uint flags; };
+        // buffer SSBO { Header header; vec4 data[]; };
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint GLCompute %main "main" %ssbo
+            OpExecutionMode %main LocalSize 1 1 1
+            OpMemberDecorate %header 0 Offset 0
+            OpMemberDecorate %header 1 Offset 4
+            OpDecorate %rt_v4float ArrayStride 16
+            OpDecorate %block Block
+            OpMemberDecorate %block 0 Offset 0
+            OpMemberDecorate %block 1 Offset 16
+            OpDecorate %ssbo DescriptorSet 0
+            OpDecorate %ssbo Binding 0
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %uint = OpTypeInt 32 0
+            %float = OpTypeFloat 32
+            %v4float = OpTypeVector %float 4
+            %header = OpTypeStruct %uint %uint
+            %rt_v4float = OpTypeRuntimeArray %v4float
+            %block = OpTypeStruct %header %rt_v4float
+            %_ptr_StorageBuffer_block = OpTypePointer StorageBuffer %block
+            %ssbo = OpVariable %_ptr_StorageBuffer_block StorageBuffer
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        let block = compiler
+            .shader_resources()?
+            .all_resources()?
+            .storage_buffers[0]
+            .base_type_id;
+
+        let element = compiler
+            .runtime_array_element(block)?
+            .expect("SSBO ends with a runtime array");
+        assert_eq!(16, element.stride);
+        assert_eq!(TypeKind::Vector, compiler.type_kind(element.element_type)?);
+
+        let TypeInner::Struct(ssbo) = compiler.type_description(block)?.inner else {
+            panic!("SSBO is not a struct");
+        };
+
+        let header = ssbo.members[0].id;
+        assert!(compiler.runtime_array_element(header)?.is_none());
+
+        let TypeInner::Struct(header) = compiler.type_description(header)?.inner else {
+            panic!("header is not a struct");
+        };
+        assert!(matches!(
+            compiler.runtime_array_element(header.members[0].id),
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn absolute_member_offset() -> Result<(), SpirvCrossError> {
+        // struct Light { vec3 position; float radius; };
+        // struct Scene { vec4 ambient; Light lights[2]; };
+        // uniform UBO { float time; Scene scene; };
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint GLCompute %main "main" %ubo
+            OpExecutionMode %main LocalSize 1 1 1
+            OpMemberDecorate %light 0 Offset 0
+            OpMemberDecorate %light 1 Offset 12
+            OpDecorate %arr_light ArrayStride 16
+            OpMemberDecorate %scene 0 Offset 0
+            OpMemberDecorate %scene 1 Offset 16
+            OpDecorate %block Block
+            OpMemberDecorate %block 0 Offset 0
+            OpMemberDecorate %block 1 Offset 16
+            OpDecorate %ubo DescriptorSet 0
+            OpDecorate %ubo Binding 0
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %float = OpTypeFloat 32
+            %v3float = OpTypeVector %float 3
+            %v4float = OpTypeVector %float 4
+            %uint = OpTypeInt 32 0
+            %uint_2 = OpConstant %uint 2
+            %light = OpTypeStruct %v3float %float
+            %arr_light = OpTypeArray %light %uint_2
+            %scene = OpTypeStruct %v4float %arr_light
+            %block = OpTypeStruct %float %scene
+            %_ptr_Uniform_block = OpTypePointer Uniform %block
+            %ubo = OpVariable %_ptr_Uniform_block Uniform
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        let block = compiler
+            .shader_resources()?
+            .all_resources()?
+            .uniform_buffers[0]
+            .base_type_id;
+
+        assert_eq!(0, compiler.absolute_member_offset(block, &[])?);
+        assert_eq!(0, compiler.absolute_member_offset(block, &[0])?);
+        assert_eq!(16, compiler.absolute_member_offset(block, &[1])?);
+        assert_eq!(16, compiler.absolute_member_offset(block, &[1, 0])?);
+        assert_eq!(32, compiler.absolute_member_offset(block, &[1, 1])?);
+
+        // The path continues into the first element of the array of lights.
+        assert_eq!(32, compiler.absolute_member_offset(block, &[1, 1, 0])?);
+        assert_eq!(44, compiler.absolute_member_offset(block, &[1, 1, 1])?);
+
+        assert!(matches!(
+            compiler.absolute_member_offset(block, &[2]),
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            compiler.absolute_member_offset(block, &[0, 0]),
+            Err(SpirvCrossError::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn variable_storage_class() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Fragment %main "main" %ubo %ssbo %tex %in_color %out_color
+            OpExecutionMode %main OriginUpperLeft
+            OpDecorate %ubo_block Block
+            OpMemberDecorate %ubo_block 0 Offset 0
+            OpDecorate %ubo DescriptorSet 0
+            OpDecorate %ubo Binding 0
+            OpDecorate %ssbo_block Block
+            OpMemberDecorate %ssbo_block 0 Offset 0
+            OpDecorate %ssbo DescriptorSet 0
+            OpDecorate %ssbo Binding 1
+            OpDecorate %tex DescriptorSet 0
+            OpDecorate %tex Binding 2
+            OpDecorate %in_color Location 0
+            OpDecorate %out_color Location 0
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %float = OpTypeFloat 32
+            %v4float = OpTypeVector %float 4
+            %ubo_block = OpTypeStruct %v4float
+            %_ptr_Uniform_ubo_block = OpTypePointer Uniform %ubo_block
+            %ubo = OpVariable %_ptr_Uniform_ubo_block Uniform
+            %ssbo_block = OpTypeStruct %v4float
+            %_ptr_StorageBuffer_ssbo_block = OpTypePointer StorageBuffer %ssbo_block
+            %ssbo = OpVariable %_ptr_StorageBuffer_ssbo_block StorageBuffer
+            %image = OpTypeImage %float 2D 0 0 0 2 Rgba8
+            %_ptr_UniformConstant_image = OpTypePointer UniformConstant %image
+            %tex = OpVariable %_ptr_UniformConstant_image UniformConstant
+            %_ptr_Input_v4float = OpTypePointer Input %v4float
+            %in_color = OpVariable %_ptr_Input_v4float Input
+            %_ptr_Output_v4float = OpTypePointer Output %v4float
+            %out_color = OpVariable %_ptr_Output_v4float Output
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        let resources = compiler.shader_resources()?.all_resources()?;
+
+        assert_eq!(
+            StorageClass::Uniform,
+            compiler.variable_storage_class(resources.uniform_buffers[0].id)?
+        );
+        assert_eq!(
+            StorageClass::StorageBuffer,
+            compiler.variable_storage_class(resources.storage_buffers[0].id)?
+        );
+        assert_eq!(
+            StorageClass::UniformConstant,
+            compiler.variable_storage_class(resources.storage_images[0].id)?
+        );
+        assert_eq!(
+            StorageClass::Input,
+            compiler.variable_storage_class(resources.stage_inputs[0].id)?
+        );
+        assert_eq!(
+            StorageClass::Output,
+            compiler.variable_storage_class(resources.stage_outputs[0].id)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn shared_memory_size() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        assert_eq!(0, compiler.shared_memory_size()?);
+
+        // shared float values[64];
+        // shared vec4 total;
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint GLCompute %main "main"
+            OpExecutionMode %main LocalSize 64 1 1
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %float = OpTypeFloat 32
+            %v4float = OpTypeVector %float 4
+            %uint = OpTypeInt 32 0
+            %uint_64 = OpConstant %uint 64
+            %arr_float = OpTypeArray %float %uint_64
+            %_ptr_Workgroup_arr_float = OpTypePointer Workgroup %arr_float
+            %values = OpVariable %_ptr_Workgroup_arr_float Workgroup
+            %_ptr_Workgroup_v4float = OpTypePointer Workgroup %v4float
+            %total = OpVariable %_ptr_Workgroup_v4float Workgroup
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        assert_eq!(64 * 4 + 16, compiler.shared_memory_size()?);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn type_is_forward_pointer() -> Result<(), SpirvCrossError> {
+        // layout(buffer_reference) buffer Node { uint value; Node next; };
+        // layout(push_constant) uniform Push { Node head; };
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpCapability PhysicalStorageBufferAddresses
+            OpMemoryModel PhysicalStorageBuffer64 GLSL450
+            OpEntryPoint GLCompute %main "main" %pc
+            OpExecutionMode %main LocalSize 1 1 1
+            OpDecorate %node Block
+            OpMemberDecorate %node 0 Offset 0
+            OpMemberDecorate %node 1 Offset 8
+            OpDecorate %pc_block Block
+            OpMemberDecorate %pc_block 0 Offset 0
+            OpTypeForwardPointer %node_ptr PhysicalStorageBuffer
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %uint = OpTypeInt 32 0
+            %node = OpTypeStruct %uint %node_ptr
+            %node_ptr = OpTypePointer PhysicalStorageBuffer %node
+            %pc_block = OpTypeStruct %node_ptr
+            %_ptr_PushConstant_pc_block = OpTypePointer PushConstant %pc_block
+            %pc = OpVariable %_ptr_PushConstant_pc_block PushConstant
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+        let pc_block = compiler
+            .shader_resources()?
+            .all_resources()?
+            .push_constant_buffers[0]
+            .base_type_id;
+
+        let TypeInner::Struct(push) = compiler.type_description(pc_block)?.inner else {
+            panic!("push constant block is not a struct");
+        };
+
+        let node_ptr = push.members[0].id;
+        assert!(compiler.type_is_forward_pointer(node_ptr)?);
+        assert!(!compiler.type_is_forward_pointer(pc_block)?);
+
+        let TypeInner::Pointer { base, .. } = compiler.type_description(node_ptr)?.inner else {
+            panic!("head is not a pointer");
+        };
+        let TypeInner::Struct(node) = compiler.type_description(base)?.inner else {
+            panic!("node is not a struct");
+        };
+
+        assert!(!compiler.type_is_forward_pointer(node.members[0].id)?);
+        assert!(compiler.type_is_forward_pointer(node.members[1].id)?);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,306 @@
+use crate::error;
+use crate::reflect::{DescriptorCount, ResourceType, TypeInner};
+use crate::Compiler;
+use std::ops::{BitOr, BitOrAssign};
+
+/// The type of a descriptor, corresponding to `VkDescriptorType`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DescriptorType {
+    /// `VK_DESCRIPTOR_TYPE_SAMPLER`
+    Sampler,
+    /// `VK_DESCRIPTOR_TYPE_COMBINED_IMAGE_SAMPLER`
+    CombinedImageSampler,
+    /// `VK_DESCRIPTOR_TYPE_SAMPLED_IMAGE`
+    SampledImage,
+    /// `VK_DESCRIPTOR_TYPE_STORAGE_IMAGE`
+    StorageImage,
+    /// `VK_DESCRIPTOR_TYPE_UNIFORM_TEXEL_BUFFER`
+    UniformTexelBuffer,
+    /// `VK_DESCRIPTOR_TYPE_STORAGE_TEXEL_BUFFER`
+    StorageTexelBuffer,
+    /// `VK_DESCRIPTOR_TYPE_UNIFORM_BUFFER`
+    UniformBuffer,
+    /// `VK_DESCRIPTOR_TYPE_STORAGE_BUFFER`
+    StorageBuffer,
+    /// `VK_DESCRIPTOR_TYPE_INPUT_ATTACHMENT`
+    InputAttachment,
+    /// `VK_DESCRIPTOR_TYPE_ACCELERATION_STRUCTURE_KHR`
+    AccelerationStructure,
+}
+
+/// A set of shader stages, with the same bit values as `VkShaderStageFlags`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ShaderStageFlags(pub u32);
+
+impl ShaderStageFlags {
+    /// `VK_SHADER_STAGE_VERTEX_BIT`
+    pub const VERTEX: Self = Self(0x1);
+    /// `VK_SHADER_STAGE_TESSELLATION_CONTROL_BIT`
+    pub const TESSELLATION_CONTROL: Self = Self(0x2);
+    /// `VK_SHADER_STAGE_TESSELLATION_EVALUATION_BIT`
+    pub const TESSELLATION_EVALUATION: Self = Self(0x4);
+    /// `VK_SHADER_STAGE_GEOMETRY_BIT`
+    pub const GEOMETRY: Self = Self(0x8);
+    /// `VK_SHADER_STAGE_FRAGMENT_BIT`
+    pub const FRAGMENT: Self = Self(0x10);
+    /// `VK_SHADER_STAGE_COMPUTE_BIT`
+    pub const COMPUTE: Self = Self(0x20);
+    /// `VK_SHADER_STAGE_TASK_BIT_EXT`
+    pub const TASK: Self = Self(0x40);
+    /// `VK_SHADER_STAGE_MESH_BIT_EXT`
+    pub const MESH: Self = Self(0x80);
+    /// `VK_SHADER_STAGE_RAYGEN_BIT_KHR`
+    pub const RAYGEN: Self = Self(0x100);
+    /// `VK_SHADER_STAGE_ANY_HIT_BIT_KHR`
+    pub const ANY_HIT: Self = Self(0x200);
+    /// `VK_SHADER_STAGE_CLOSEST_HIT_BIT_KHR`
+    pub const CLOSEST_HIT: Self = Self(0x400);
+    /// `VK_SHADER_STAGE_MISS_BIT_KHR`
+    pub const MISS: Self = Self(0x800);
+    /// `VK_SHADER_STAGE_INTERSECTION_BIT_KHR`
+    pub const INTERSECTION: Self = Self(0x1000);
+    /// `VK_SHADER_STAGE_CALLABLE_BIT_KHR`
+    pub const CALLABLE: Self = Self(0x2000);
+
+    /// Get the shader stage for an execution model.
+    ///
+    /// Returns an empty set for execution models that are not supported by Vulkan.
+    pub fn from_execution_model(model: spirv::ExecutionModel) -> Self {
+        use spirv::ExecutionModel;
+        match model {
+            ExecutionModel::Vertex => Self::VERTEX,
+            ExecutionModel::TessellationControl => Self::TESSELLATION_CONTROL,
+            ExecutionModel::TessellationEvaluation => Self::TESSELLATION_EVALUATION,
+            ExecutionModel::Geometry => Self::GEOMETRY,
+            ExecutionModel::Fragment => Self::FRAGMENT,
+            ExecutionModel::GLCompute => Self::COMPUTE,
+            ExecutionModel::TaskEXT | ExecutionModel::TaskNV => Self::TASK,
+            ExecutionModel::MeshEXT | ExecutionModel::MeshNV => Self::MESH,
+            ExecutionModel::RayGenerationKHR => Self::RAYGEN,
+            ExecutionModel::AnyHitKHR => Self::ANY_HIT,
+            ExecutionModel::ClosestHitKHR => Self::CLOSEST_HIT,
+            ExecutionModel::MissKHR => Self::MISS,
+            ExecutionModel::IntersectionKHR => Self::INTERSECTION,
+            ExecutionModel::CallableKHR => Self::CALLABLE,
+            _ => Self(0),
+        }
+    }
+
+    /// Whether all stages in `other` are contained in this set.
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for ShaderStageFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for ShaderStageFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// A binding in a descriptor set layout, corresponding to `VkDescriptorSetLayoutBinding`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DescriptorSetLayoutBindingDesc {
+    /// The binding number of the descriptor.
+    pub binding: u32,
+    /// The type of the descriptor.
+    pub descriptor_type: DescriptorType,
+    /// The number of descriptors in the binding.
+    pub count: DescriptorCount,
+    /// The shader stages that can access the binding.
+    pub stage_flags: ShaderStageFlags,
+}
+
+/// The bindings of a descriptor set layout, corresponding to `VkDescriptorSetLayoutCreateInfo`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DescriptorSetLayoutDesc {
+    /// The descriptor set number.
+    pub set: u32,
+    /// The bindings in the descriptor set, sorted by binding number.
+    pub bindings: Vec<DescriptorSetLayoutBindingDesc>,
+}
+
+/// Get the descriptor set layouts used by the current entry point of the compiler,
+/// sorted by descriptor set number.
+///
+/// The layouts are built from [`Compiler::descriptor_set_layout_bindings`], and follow
+/// the same rules: resources without a `Binding` decoration are ignored, and resources
+/// without a `DescriptorSet` decoration are considered to be in descriptor set 0.
+/// The stage flags of every binding are set to the stage of the current entry point.
+/// If several resources alias the same binding, the binding is described by the first resource.
+///
+/// Atomic counters, which are not supported by Vulkan, are ignored.
+pub fn descriptor_set_layouts<T>(
+    compiler: &Compiler<T>,
+) -> error::Result<Vec<DescriptorSetLayoutDesc>> {
+    let mut layouts: Vec<DescriptorSetLayoutDesc> = Vec::new();
+
+    // The bindings are sorted by set and binding, so each layout is sorted as well.
+    for resource in compiler.descriptor_set_layout_bindings()? {
+        if resource.kind == ResourceType::AtomicCounter {
+            continue;
+        }
+
+        let mut base = compiler.variable_type(resource.id)?;
+        let is_texel_buffer = loop {
+            match compiler.type_description(base)?.inner {
+                TypeInner::Pointer { base: inner, .. } | TypeInner::Array { base: inner, .. } => {
+                    base = inner
+                }
+                TypeInner::Image(image) => break image.dimension == spirv::Dim::DimBuffer,
+                _ => break false,
+            }
+        };
+
+        let descriptor_type = match (resource.kind, is_texel_buffer) {
+            (ResourceType::UniformBuffer, _) => DescriptorType::UniformBuffer,
+            (ResourceType::StorageBuffer, _) => DescriptorType::StorageBuffer,
+            (ResourceType::SubpassInput, _) => DescriptorType::InputAttachment,
+            (ResourceType::StorageImage, true) => DescriptorType::StorageTexelBuffer,
+            (ResourceType::StorageImage, false) => DescriptorType::StorageImage,
+            (ResourceType::SampledImage | ResourceType::SeparateImage, true) => {
+                DescriptorType::UniformTexelBuffer
+            }
+            (ResourceType::SampledImage, false) => DescriptorType::CombinedImageSampler,
+            (ResourceType::SeparateImage, false) => DescriptorType::SampledImage,
+            (ResourceType::AccelerationStructure, _) => DescriptorType::AccelerationStructure,
+            // Only separate samplers remain.
+            _ => DescriptorType::Sampler,
+        };
+
+        let layout = match layouts.last_mut() {
+            Some(layout) if layout.set == resource.set => layout,
+            _ => {
+                layouts.push(DescriptorSetLayoutDesc {
+                    set: resource.set,
+                    bindings: Vec::new(),
+                });
+                layouts.last_mut().unwrap()
+            }
+        };
+
+        if layout
+            .bindings
+            .last()
+            .map_or(false, |desc| desc.binding == resource.binding)
+        {
+            continue;
+        }
+
+        layout.bindings.push(DescriptorSetLayoutBindingDesc {
+            binding: resource.binding,
+            descriptor_type,
+            count: resource.count,
+            stage_flags: ShaderStageFlags::from_execution_model(resource.stage),
+        });
+    }
+
+    Ok(layouts)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::SpirvCrossError;
+    use crate::reflect::vulkan::{descriptor_set_layouts, DescriptorType, ShaderStageFlags};
+    use crate::reflect::DescriptorCount;
+    use crate::Compiler;
+    use crate::{targets, Module};
+
+    static BASIC_SPV: &[u8] = include_bytes!("../../basic.spv");
+
+    #[test]
+    pub fn descriptor_set_layouts_test() -> Result<(), SpirvCrossError> {
+        let vec = Vec::from(BASIC_SPV);
+        let words = Module::from_words(bytemuck::cast_slice(&vec));
+
+        let compiler: Compiler<targets::None> = Compiler::new(words)?;
+        let layouts = descriptor_set_layouts(&compiler)?;
+
+        assert_eq!(1, layouts.len());
+        assert_eq!(0, layouts[0].set);
+
+        let bindings = &layouts[0].bindings;
+        assert_eq!(2, bindings.len());
+        assert_eq!(DescriptorType::UniformBuffer, bindings[0].descriptor_type);
+        assert_eq!(
+            DescriptorType::CombinedImageSampler,
+            bindings[1].descriptor_type
+        );
+        assert_eq!(DescriptorCount::Fixed(1), bindings[1].count);
+        assert_eq!(ShaderStageFlags::FRAGMENT, bindings[1].stage_flags);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn descriptor_set_layouts_match_bindings() -> Result<(), SpirvCrossError> {
+        let words = Module::from_spirv_asm(
+            r#"
+            OpCapability Shader
+            OpCapability SampledBuffer
+            OpMemoryModel Logical GLSL450
+            OpEntryPoint Fragment %main "main"
+            OpExecutionMode %main OriginUpperLeft
+            OpDecorate %block Block
+            OpMemberDecorate %block 0 Offset 0
+            OpDecorate %first DescriptorSet 1
+            OpDecorate %first Binding 2
+            OpDecorate %second DescriptorSet 1
+            OpDecorate %second Binding 2
+            OpDecorate %texel_buffer Binding 0
+            %void = OpTypeVoid
+            %fn = OpTypeFunction %void
+            %float = OpTypeFloat 32
+            %block = OpTypeStruct %float
+            %_ptr_Uniform_block = OpTypePointer Uniform %block
+            %first = OpVariable %_ptr_Uniform_block Uniform
+            %second = OpVariable %_ptr_Uniform_block Uniform
+            %buffer_image = OpTypeImage %float Buffer 0 0 0 1 Unknown
+            %_ptr_UniformConstant_buffer_image = OpTypePointer UniformConstant %buffer_image
+            %texel_buffer = OpVariable %_ptr_UniformConstant_buffer_image UniformConstant
+            %sampler_type = OpTypeSampler
+            %_ptr_UniformConstant_sampler = OpTypePointer UniformConstant %sampler_type
+            %unbound = OpVariable %_ptr_UniformConstant_sampler UniformConstant
+            %main = OpFunction %void None %fn
+            %entry = OpLabel
+            OpReturn
+            OpFunctionEnd
+            "#,
+        )?;
+
+        let compiler: Compiler<targets::None> = Compiler::new(Module::from_words(&words))?;
+
+        // Both aliases are reported, the unbound sampler is not.
+        let bindings = compiler.descriptor_set_layout_bindings()?;
+        assert_eq!(3, bindings.len());
+
+        let layouts = descriptor_set_layouts(&compiler)?;
+        assert_eq!(2, layouts.len());
+
+        assert_eq!(0, layouts[0].set);
+        assert_eq!(1, layouts[0].bindings.len());
+        assert_eq!(0, layouts[0].bindings[0].binding);
+        assert_eq!(
+            DescriptorType::UniformTexelBuffer,
+            layouts[0].bindings[0].descriptor_type
+        );
+
+        assert_eq!(1, layouts[1].set);
+        assert_eq!(1, layouts[1].bindings.len());
+        assert_eq!(2, layouts[1].bindings[0].binding);
+        assert_eq!(
+            DescriptorType::UniformBuffer,
+            layouts[1].bindings[0].descriptor_type
+        );
+
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 use crate::cell::AllocationDropGuard;
-use crate::SpirvCrossError;
+use crate::sealed::Sealed;
+use crate::{SpirvCrossError, ToStatic};
 use std::borrow::Cow;
 use std::ffi::{c_char, CStr, CString};
 use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
 /// An immutable wrapper around a valid UTF-8 string whose memory contents
@@ -11,7 +13,7 @@ use std::ops::Deref;
 /// In most cases, users of this library do not need to worry about
 /// constructing a [`CompilerStr`]. All functions that take strings
 /// take `impl Into<CompilerStr<'_>>`, which converts automatically from
-/// [`&str`](str) and [`String`](String).
+/// [`&str`](str), [`String`](String) and [`&CStr`](CStr).
 ///
 /// [`CompilerStr`] also implements [`Deref`](Deref) for [`&str`](str),
 /// so all immutable `str` methods are available.
@@ -28,9 +30,9 @@ use std::ops::Deref;
 /// If the provenance of the string is an owned Rust `String`, then an allocation
 /// will occur only if necessary to append a nul byte.
 ///
-/// If the provenance of the string is a `&CStr`, or
-/// with lifetime longer than `'a`, then an allocation will not occur
-/// when passing the string to FFI.
+/// If the provenance of the string is a valid UTF-8 `&CStr` with lifetime
+/// longer than `'a`, then an allocation will not occur when passing the
+/// string to FFI.
 ///
 /// Using [C-string literals](https://doc.rust-lang.org/edition-guide/rust-2021/c-string-literals.html)
 /// where possible can be used to avoid an allocation.
@@ -87,12 +89,43 @@ impl Clone for ContextPointer<'_> {
     }
 }
 
+impl Sealed for CompilerStr<'_> {}
+impl ToStatic for CompilerStr<'_> {
+    type Static<'a>
+    = CompilerStr<'static>
+    where
+        'a: 'static;
+
+    /// Copy the string into an owned `CompilerStr`.
+    ///
+    /// The returned string does not keep the SPIRV-Cross context alive.
+    fn to_static(&self) -> Self::Static<'static> {
+        CompilerStr::from_string(self.cow.to_string())
+    }
+}
+
 impl<'a> Display for CompilerStr<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.cow)
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for CompilerStr<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_ref())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for CompilerStr<'_> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <String as serde::Deserialize>::deserialize(deserializer).map(CompilerStr::from_string)
+    }
+}
+
 impl<'a> Debug for CompilerStr<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self.cow)
@@ -171,8 +204,22 @@ impl PartialEq<String> for CompilerStr<'_> {
     }
 }
 
+impl<'a> PartialEq<&'a CStr> for CompilerStr<'_> {
+    fn eq(&self, other: &&'a CStr) -> bool {
+        self.cow.as_bytes() == other.to_bytes()
+    }
+}
+
 impl Eq for CompilerStr<'_> {}
 
+/// Hashes only the contents of the string, consistent with [`PartialEq`] and
+/// [`str`], regardless of where the string originated from.
+impl Hash for CompilerStr<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.cow.as_ref().hash(state)
+    }
+}
+
 impl From<String> for CompilerStr<'_> {
     fn from(value: String) -> Self {
         Self::from_string(value)
@@ -277,10 +324,33 @@ impl<'a> CompilerStr<'a> {
         }
     }
 
+    /// Wrap a Rust `&CStr`.
+    ///
+    /// If the string is valid UTF-8, this will not allocate when exposing to C.
     pub(crate) fn from_cstr(cstr: &'a CStr) -> Self {
-        Self {
-            pointer: Some(ContextPointer::BorrowedCStr(cstr)),
-            cow: cstr.to_string_lossy(),
+        let cow = cstr.to_string_lossy();
+        let pointer = match &cow {
+            Cow::Borrowed(_) => Some(ContextPointer::BorrowedCStr(cstr)),
+            // The lossy string no longer matches the original C string.
+            Cow::Owned(_) => None,
+        };
+
+        Self { pointer, cow }
+    }
+
+    /// Get the string as a nul-terminated [`CStr`] without allocating.
+    ///
+    /// Returns `None` if the string did not originate from a C string,
+    /// i.e. it was created from a Rust [`&str`](str) or [`String`], or if
+    /// the original C string was not valid UTF-8.
+    pub fn as_cstr(&self) -> Option<&CStr> {
+        match self.pointer.as_ref()? {
+            // SAFETY: the pointer is a valid nul-terminated C string that lives
+            // as long as the context, which is kept alive by `self`.
+            ContextPointer::FromContext { pointer, .. } => {
+                Some(unsafe { CStr::from_ptr(*pointer) })
+            }
+            ContextPointer::BorrowedCStr(cstr) => Some(cstr),
         }
     }
 
@@ -313,7 +383,7 @@ impl<'a> CompilerStr<'a> {
 
 #[cfg(test)]
 mod test {
-    use crate::string::CompilerStr;
+    use crate::string::{CompilerStr, MaybeOwnedCString};
     use std::ffi::{c_char, CStr, CString};
     use std::sync::Arc;
 
@@ -392,16 +462,53 @@ mod test {
     //     // lt.borrow_mut().set(cstr)
     // }
     //
-    // #[test]
-    // fn test_cstr_does_not_allocate() {
-    //     // can't use cstring literals until 1.77
-    //     let str = unsafe { CStr::from_ptr(b"Hello\0".as_ptr().cast()) };
-    //
-    //     let original_ptr = str.as_ptr() as usize;
-    //     let ctxstr = ContextStr::<LifetimeContext>::from(str);
-    //
-    //     let new_ptr = ctxstr.into_cstring_ptr().unwrap().as_ptr();
-    //     assert_eq!(original_ptr, new_ptr as usize);
-    //     // lt.borrow_mut().set(cstr)
-    // }
+    #[test]
+    fn as_cstr() {
+        let cstr = unsafe { CStr::from_ptr(b"Hello\0".as_ptr().cast()) };
+
+        let borrowed = CompilerStr::from(cstr);
+        assert_eq!(Some(cstr.as_ptr()), borrowed.as_cstr().map(CStr::as_ptr));
+
+        let owned = CompilerStr::from(String::from("Hello"));
+        assert_eq!(None, owned.as_cstr());
+    }
+
+    #[test]
+    fn test_cstr_does_not_allocate() {
+        // can't use cstring literals until 1.77
+        let str = unsafe { CStr::from_ptr(b"Hello\0".as_ptr().cast()) };
+
+        let original_ptr = str.as_ptr() as usize;
+        let ctxstr = CompilerStr::from(str);
+
+        let cstring = ctxstr.into_cstring_ptr().unwrap();
+        assert!(matches!(cstring, MaybeOwnedCString::Borrowed(_)));
+        assert_eq!(original_ptr, cstring.as_ptr() as usize);
+    }
+
+    #[test]
+    fn hash_ignores_provenance() {
+        use std::collections::HashSet;
+
+        let cstr = unsafe { CStr::from_ptr(b"Hello\0".as_ptr().cast()) };
+
+        let mut set = HashSet::new();
+        set.insert(CompilerStr::from(cstr));
+        set.insert(CompilerStr::from("Hello"));
+        set.insert(CompilerStr::from(String::from("Hello")));
+
+        assert_eq!(1, set.len());
+        assert_eq!(CompilerStr::from("Hello"), cstr);
+    }
+
+    #[test]
+    fn test_invalid_utf8_cstr_does_allocate() {
+        let str = unsafe { CStr::from_ptr(b"Hello\xff\0".as_ptr().cast()) };
+
+        let ctxstr = CompilerStr::from(str);
+        assert_eq!(None, ctxstr.as_cstr());
+
+        let cstring = ctxstr.into_cstring_ptr().unwrap();
+        assert!(matches!(cstring, MaybeOwnedCString::Owned(_)));
+    }
 }
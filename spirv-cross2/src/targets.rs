@@ -3,8 +3,16 @@ use crate::compile::CompilableTarget;
 use crate::sealed::Sealed;
 use spirv_cross_sys::CompilerBackend;
 
+/// The backend of a compiler instance, returned by [`Compiler::backend`](crate::Compiler::backend).
+pub use spirv_cross_sys::CompilerBackend as Backend;
+
 /// Reflection only backend, no compilation features
 /// enabled.
+///
+/// Methods that mutate the module, such as [`Compiler::set_decoration`](crate::Compiler::set_decoration)
+/// and [`Compiler::set_member_decoration`](crate::Compiler::set_member_decoration), are available
+/// for every target, including this one. However, SPIRV-Cross can not serialize a
+/// modified module back to SPIR-V, so changes are only visible through reflection.
 pub struct None;
 
 /// Compile SPIR-V to GLSL.
@@ -48,6 +56,37 @@ mod glsl {
     use super::*;
     impl CompilableTarget for Glsl {
         type Options = compile::glsl::CompilerOptions;
+
+        fn prepare(
+            compiler: &mut crate::Compiler<Self>,
+            options: &Self::Options,
+        ) -> crate::error::Result<()> {
+            if options.emit_invariant_position {
+                compiler.set_position_invariant()?;
+            }
+            Ok(())
+        }
+
+        #[allow(deprecated)]
+        unsafe fn apply_local_options(
+            options: &Self::Options,
+            handle: spirv_cross_sys::spvc_compiler_options,
+            root: impl crate::ContextRooted + Copy,
+        ) -> crate::error::Result<()> {
+            use crate::error::ToContextError;
+
+            if options.seperate_shader_objects {
+                unsafe {
+                    spirv_cross_sys::spvc_compiler_options_set_bool(
+                        handle,
+                        spirv_cross_sys::spvc_compiler_option::SPVC_COMPILER_OPTION_GLSL_SEPARATE_SHADER_OBJECTS,
+                        true,
+                    )
+                    .ok(root)?;
+                }
+            }
+            Ok(())
+        }
     }
     impl Sealed for Glsl {}
     impl Target for Glsl {